@@ -13,7 +13,10 @@ ChatListResult useChatList(String pubkey) {
   final refreshKey = useState(0);
 
   final stream = useMemoized(
-    () => subscribeToChatList(accountPubkey: pubkey).map((item) {
+    () => subscribeToChatList(
+      accountPubkey: pubkey,
+      filter: ChatListFilter.active,
+    ).map((item) {
       return item.when(
         initialSnapshot: (items) {
           chatMap.value = {for (final c in items.reversed) c.mlsGroupId: c};
@@ -29,6 +32,14 @@ ChatListResult useChatList(String pubkey) {
             case ChatListUpdateTrigger.newLastMessage:
               chatMap.value.remove(id);
               chatMap.value[id] = update.item;
+            case ChatListUpdateTrigger.chatArchived:
+            case ChatListUpdateTrigger.groupLeft:
+              chatMap.value.remove(id);
+            case ChatListUpdateTrigger.chatUnarchived:
+            case ChatListUpdateTrigger.groupMetadataChanged:
+            case ChatListUpdateTrigger.groupMembersChanged:
+            case ChatListUpdateTrigger.groupAdminsChanged:
+              chatMap.value[id] = update.item;
           }
           return chatMap.value;
         },
@@ -4,14 +4,21 @@ import 'package:whitenoise/models/reply_preview.dart';
 import 'package:whitenoise/services/user_service.dart';
 import 'package:whitenoise/src/rust/api/messages.dart';
 import 'package:whitenoise/src/rust/api/metadata.dart';
+import 'package:whitenoise/src/rust/api/outbox.dart';
+
+const _pageSize = 50;
 
 typedef ChatMessagesResult = ({
   int messageCount,
   ChatMessage Function(int reversedIndex) getMessage,
   int? Function(String messageId) getReversedMessageIndex,
   bool isLoading,
+  bool hasMoreBefore,
+  Future<void> Function() loadOlderMessages,
+  List<OutboxMessage> outboxMessages,
   String? latestMessageId,
   String? latestMessagePubkey,
+  FlutterMetadata? Function(String pubkey) getAuthorMetadata,
   ReplyPreview? Function(String? replyId) getReplyPreview,
 });
 
@@ -19,13 +26,44 @@ ChatMessagesResult useChatMessages(String groupId) {
   final messageIds = useRef<List<String>>([]);
   final messagesById = useRef<Map<String, ChatMessage>>({});
   final indexById = useRef<Map<String, int>>({});
+  final subscriptionId = useRef<String?>(null);
+  final hasMoreBefore = useRef(false);
+  final isLoadingOlder = useRef(false);
+  final outboxByLocalId = useRef<Map<String, OutboxMessage>>({});
   final authorsMetadataByPubkey = useState<Map<String, FlutterMetadata>>({});
   final loadingPubkeys = useRef<Set<String>>({});
 
+  ({
+    int messageCount,
+    List<OutboxMessage> outboxMessages,
+    String? latestMessageId,
+    String? latestMessagePubkey,
+  })
+  currentState() {
+    // System entries are timeline notices, not messages
+    ChatMessage? lastMessage;
+    for (final id in messageIds.value.reversed) {
+      final message = messagesById.value[id];
+      if (message != null && message.systemEvent == null) {
+        lastMessage = message;
+        break;
+      }
+    }
+    return (
+      messageCount: messageIds.value.length,
+      outboxMessages: outboxByLocalId.value.values.toList(),
+      latestMessageId: lastMessage?.id,
+      latestMessagePubkey: lastMessage?.pubkey,
+    );
+  }
+
   final stream = useMemoized(
-    () => subscribeToGroupMessages(groupId: groupId).map((item) {
+    () => subscribeToGroupMessages(groupId: groupId, snapshotLimit: _pageSize).map((item) {
       return item.when(
-        initialSnapshot: (initialChatMessages) {
+        initialSnapshot: (id, initialChatMessages, hasMore) {
+          subscriptionId.value = id;
+          hasMoreBefore.value = hasMore;
+          isLoadingOlder.value = false;
           messageIds.value = [];
           messagesById.value = {};
           indexById.value = {};
@@ -37,12 +75,7 @@ ChatMessagesResult useChatMessages(String groupId) {
             indexById.value[message.id] = i;
           }
 
-          final lastMessage = initialChatMessages.isNotEmpty ? initialChatMessages.last : null;
-          return (
-            messageCount: initialChatMessages.length,
-            latestMessageId: lastMessage?.id,
-            latestMessagePubkey: lastMessage?.pubkey,
-          );
+          return currentState();
         },
         update: (update) {
           final message = update.message;
@@ -56,13 +89,34 @@ ChatMessagesResult useChatMessages(String groupId) {
             indexById.value[message.id] = newIndex;
           }
 
-          final lastId = messageIds.value.isNotEmpty ? messageIds.value.last : null;
-          final lastPubkey = lastId != null ? messagesById.value[lastId]?.pubkey : null;
-          return (
-            messageCount: messageIds.value.length,
-            latestMessageId: lastId,
-            latestMessagePubkey: lastPubkey,
-          );
+          return currentState();
+        },
+        // Published messages arrive through the timeline, only keep the
+        // ones still queued, publishing or failed
+        outboxUpdate: (message) {
+          if (message.status is OutboxStatus_Published) {
+            outboxByLocalId.value.remove(message.localId);
+          } else {
+            outboxByLocalId.value[message.localId] = message;
+          }
+          return currentState();
+        },
+        olderPage: (olderMessages, hasMore) {
+          hasMoreBefore.value = hasMore;
+          isLoadingOlder.value = false;
+          final olderIds = [
+            for (final message in olderMessages)
+              if (!messagesById.value.containsKey(message.id)) message.id,
+          ];
+          for (final message in olderMessages) {
+            messagesById.value[message.id] = message;
+          }
+          messageIds.value = [...olderIds, ...messageIds.value];
+          indexById.value = {
+            for (var i = 0; i < messageIds.value.length; i++) messageIds.value[i]: i,
+          };
+
+          return currentState();
         },
       );
     }),
@@ -71,12 +125,26 @@ ChatMessagesResult useChatMessages(String groupId) {
 
   final initialData = (
     messageCount: 0,
+    outboxMessages: const <OutboxMessage>[],
     latestMessageId: null,
     latestMessagePubkey: null,
   );
   final snapshot = useStream(stream, initialData: initialData);
   final isLoading = snapshot.connectionState == ConnectionState.waiting;
 
+  Future<void> loadOlderMessages() async {
+    final id = subscriptionId.value;
+    if (id == null || !hasMoreBefore.value || isLoadingOlder.value) return;
+
+    isLoadingOlder.value = true;
+    try {
+      await loadOlderGroupMessages(subscriptionId: id, limit: _pageSize);
+    } catch (_) {
+      isLoadingOlder.value = false;
+      rethrow;
+    }
+  }
+
   ChatMessage getMessage(int reversedIndex) {
     final length = messageIds.value.length;
     final naturalIndex = length - 1 - reversedIndex;
@@ -144,8 +212,12 @@ ChatMessagesResult useChatMessages(String groupId) {
     getMessage: getMessage,
     getReversedMessageIndex: getReversedMessageIndex,
     isLoading: isLoading,
+    hasMoreBefore: hasMoreBefore.value,
+    loadOlderMessages: loadOlderMessages,
+    outboxMessages: snapshot.data?.outboxMessages ?? const [],
     latestMessageId: snapshot.data?.latestMessageId,
     latestMessagePubkey: snapshot.data?.latestMessagePubkey,
+    getAuthorMetadata: getAuthorMetadata,
     getReplyPreview: getReplyPreview,
   );
 }
@@ -3,16 +3,20 @@ import 'package:flutter/scheduler.dart';
 import 'package:flutter_hooks/flutter_hooks.dart';
 
 const _bottomThreshold = 50.0;
+const _topThreshold = 200.0;
 
 void useChatScroll({
   required ScrollController scrollController,
   required FocusNode focusNode,
   required String? latestMessageId,
   required bool isLatestMessageOwn,
+  VoidCallback? onNearTop,
 }) {
   final isAtBottom = useState(true);
   final prevLatestMessageId = useRef<String?>(null);
   final shouldStayAtBottom = useRef(false);
+  final onNearTopRef = useRef(onNearTop);
+  onNearTopRef.value = onNearTop;
 
   void scrollToBottom() {
     SchedulerBinding.instance.addPostFrameCallback((_) {
@@ -46,6 +50,21 @@ void useChatScroll({
     return () => scrollController.removeListener(updateIsAtBottom);
   }, [scrollController]);
 
+  useEffect(() {
+    void checkNearTop() {
+      final callback = onNearTopRef.value;
+      if (callback == null || !scrollController.hasClients) return;
+      final position = scrollController.position;
+      // The list is reversed, so the oldest messages are at the max extent
+      if (position.maxScrollExtent - position.pixels <= _topThreshold) {
+        callback();
+      }
+    }
+
+    scrollController.addListener(checkNearTop);
+    return () => scrollController.removeListener(checkNearTop);
+  }, [scrollController]);
+
   useEffect(() {
     void onFocusChange() {
       if (focusNode.hasFocus) {
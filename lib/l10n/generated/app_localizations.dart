@@ -1445,6 +1445,54 @@ abstract class AppLocalizations {
   /// In en, this message translates to:
   /// **'Failed to delete all data. Please try again.'**
   String get deleteAllDataError;
+
+  /// System timeline entry when members are added to a group
+  ///
+  /// In en, this message translates to:
+  /// **'{actor} added {members}'**
+  String timelineMembersAdded(String actor, String members);
+
+  /// System timeline entry when members are removed from a group
+  ///
+  /// In en, this message translates to:
+  /// **'{actor} removed {members}'**
+  String timelineMembersRemoved(String actor, String members);
+
+  /// System timeline entry when a member leaves a group
+  ///
+  /// In en, this message translates to:
+  /// **'{actor} left the group'**
+  String timelineMemberLeft(String actor);
+
+  /// System timeline entry when a group is renamed
+  ///
+  /// In en, this message translates to:
+  /// **'{actor} renamed the group to {name}'**
+  String timelineGroupRenamed(String actor, String name);
+
+  /// System timeline entry when the group description changes
+  ///
+  /// In en, this message translates to:
+  /// **'{actor} changed the group description'**
+  String timelineDescriptionChanged(String actor);
+
+  /// System timeline entry when the group image changes
+  ///
+  /// In en, this message translates to:
+  /// **'{actor} changed the group image'**
+  String timelineImageChanged(String actor);
+
+  /// System timeline entry when members are made admins
+  ///
+  /// In en, this message translates to:
+  /// **'{actor} made {members} admin'**
+  String timelineAdminsAdded(String actor, String members);
+
+  /// System timeline entry when members are removed as admins
+  ///
+  /// In en, this message translates to:
+  /// **'{actor} removed {members} as admin'**
+  String timelineAdminsRemoved(String actor, String members);
 }
 
 class _AppLocalizationsDelegate extends LocalizationsDelegate<AppLocalizations> {
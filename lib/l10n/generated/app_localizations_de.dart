@@ -760,4 +760,44 @@ class AppLocalizationsDe extends AppLocalizations {
 
   @override
   String get deleteAllDataError => 'Fehler beim Löschen aller Daten. Bitte versuche es erneut.';
+
+  @override
+  String timelineMembersAdded(String actor, String members) {
+    return '$actor hat $members hinzugefügt';
+  }
+
+  @override
+  String timelineMembersRemoved(String actor, String members) {
+    return '$actor hat $members entfernt';
+  }
+
+  @override
+  String timelineMemberLeft(String actor) {
+    return '$actor hat die Gruppe verlassen';
+  }
+
+  @override
+  String timelineGroupRenamed(String actor, String name) {
+    return '$actor hat die Gruppe in $name umbenannt';
+  }
+
+  @override
+  String timelineDescriptionChanged(String actor) {
+    return '$actor hat die Gruppenbeschreibung geändert';
+  }
+
+  @override
+  String timelineImageChanged(String actor) {
+    return '$actor hat das Gruppenbild geändert';
+  }
+
+  @override
+  String timelineAdminsAdded(String actor, String members) {
+    return '$actor hat $members zum Admin gemacht';
+  }
+
+  @override
+  String timelineAdminsRemoved(String actor, String members) {
+    return '$actor hat $members als Admin entfernt';
+  }
 }
@@ -743,4 +743,44 @@ class AppLocalizationsEn extends AppLocalizations {
 
   @override
   String get deleteAllDataError => 'Failed to delete all data. Please try again.';
+
+  @override
+  String timelineMembersAdded(String actor, String members) {
+    return '$actor added $members';
+  }
+
+  @override
+  String timelineMembersRemoved(String actor, String members) {
+    return '$actor removed $members';
+  }
+
+  @override
+  String timelineMemberLeft(String actor) {
+    return '$actor left the group';
+  }
+
+  @override
+  String timelineGroupRenamed(String actor, String name) {
+    return '$actor renamed the group to $name';
+  }
+
+  @override
+  String timelineDescriptionChanged(String actor) {
+    return '$actor changed the group description';
+  }
+
+  @override
+  String timelineImageChanged(String actor) {
+    return '$actor changed the group image';
+  }
+
+  @override
+  String timelineAdminsAdded(String actor, String members) {
+    return '$actor made $members admin';
+  }
+
+  @override
+  String timelineAdminsRemoved(String actor, String members) {
+    return '$actor removed $members as admin';
+  }
 }
@@ -751,4 +751,44 @@ class AppLocalizationsEs extends AppLocalizations {
   @override
   String get deleteAllDataError =>
       'No se pudieron eliminar todos los datos. Por favor, inténtalo de nuevo.';
+
+  @override
+  String timelineMembersAdded(String actor, String members) {
+    return '$actor añadió a $members';
+  }
+
+  @override
+  String timelineMembersRemoved(String actor, String members) {
+    return '$actor eliminó a $members';
+  }
+
+  @override
+  String timelineMemberLeft(String actor) {
+    return '$actor salió del grupo';
+  }
+
+  @override
+  String timelineGroupRenamed(String actor, String name) {
+    return '$actor cambió el nombre del grupo a $name';
+  }
+
+  @override
+  String timelineDescriptionChanged(String actor) {
+    return '$actor cambió la descripción del grupo';
+  }
+
+  @override
+  String timelineImageChanged(String actor) {
+    return '$actor cambió la imagen del grupo';
+  }
+
+  @override
+  String timelineAdminsAdded(String actor, String members) {
+    return '$actor hizo admin a $members';
+  }
+
+  @override
+  String timelineAdminsRemoved(String actor, String members) {
+    return '$actor quitó a $members como admin';
+  }
 }
@@ -748,4 +748,44 @@ class AppLocalizationsFr extends AppLocalizations {
   @override
   String get deleteAllDataError =>
       'Échec de la suppression de toutes les données. Veuillez réessayer.';
+
+  @override
+  String timelineMembersAdded(String actor, String members) {
+    return '$actor a ajouté $members';
+  }
+
+  @override
+  String timelineMembersRemoved(String actor, String members) {
+    return '$actor a retiré $members';
+  }
+
+  @override
+  String timelineMemberLeft(String actor) {
+    return '$actor a quitté le groupe';
+  }
+
+  @override
+  String timelineGroupRenamed(String actor, String name) {
+    return '$actor a renommé le groupe en $name';
+  }
+
+  @override
+  String timelineDescriptionChanged(String actor) {
+    return '$actor a modifié la description du groupe';
+  }
+
+  @override
+  String timelineImageChanged(String actor) {
+    return '$actor a modifié l\'image du groupe';
+  }
+
+  @override
+  String timelineAdminsAdded(String actor, String members) {
+    return '$actor a nommé $members admin';
+  }
+
+  @override
+  String timelineAdminsRemoved(String actor, String members) {
+    return '$actor a retiré le rôle d\'admin à $members';
+  }
 }
@@ -744,4 +744,44 @@ class AppLocalizationsIt extends AppLocalizations {
 
   @override
   String get deleteAllDataError => 'Impossibile eliminare tutti i dati. Riprova.';
+
+  @override
+  String timelineMembersAdded(String actor, String members) {
+    return '$actor ha aggiunto $members';
+  }
+
+  @override
+  String timelineMembersRemoved(String actor, String members) {
+    return '$actor ha rimosso $members';
+  }
+
+  @override
+  String timelineMemberLeft(String actor) {
+    return '$actor ha lasciato il gruppo';
+  }
+
+  @override
+  String timelineGroupRenamed(String actor, String name) {
+    return '$actor ha rinominato il gruppo in $name';
+  }
+
+  @override
+  String timelineDescriptionChanged(String actor) {
+    return '$actor ha modificato la descrizione del gruppo';
+  }
+
+  @override
+  String timelineImageChanged(String actor) {
+    return '$actor ha modificato l\'immagine del gruppo';
+  }
+
+  @override
+  String timelineAdminsAdded(String actor, String members) {
+    return '$actor ha reso admin $members';
+  }
+
+  @override
+  String timelineAdminsRemoved(String actor, String members) {
+    return '$actor ha rimosso $members dagli admin';
+  }
 }
@@ -748,4 +748,44 @@ class AppLocalizationsPt extends AppLocalizations {
 
   @override
   String get deleteAllDataError => 'Falha ao excluir todos os dados. Por favor, tente novamente.';
+
+  @override
+  String timelineMembersAdded(String actor, String members) {
+    return '$actor adicionou $members';
+  }
+
+  @override
+  String timelineMembersRemoved(String actor, String members) {
+    return '$actor removeu $members';
+  }
+
+  @override
+  String timelineMemberLeft(String actor) {
+    return '$actor saiu do grupo';
+  }
+
+  @override
+  String timelineGroupRenamed(String actor, String name) {
+    return '$actor renomeou o grupo para $name';
+  }
+
+  @override
+  String timelineDescriptionChanged(String actor) {
+    return '$actor alterou a descrição do grupo';
+  }
+
+  @override
+  String timelineImageChanged(String actor) {
+    return '$actor alterou a imagem do grupo';
+  }
+
+  @override
+  String timelineAdminsAdded(String actor, String members) {
+    return '$actor tornou $members admin';
+  }
+
+  @override
+  String timelineAdminsRemoved(String actor, String members) {
+    return '$actor removeu $members como admin';
+  }
 }
@@ -750,4 +750,44 @@ class AppLocalizationsRu extends AppLocalizations {
 
   @override
   String get deleteAllDataError => 'Не удалось удалить все данные. Пожалуйста, попробуйте снова.';
+
+  @override
+  String timelineMembersAdded(String actor, String members) {
+    return '$actor добавил(а) $members';
+  }
+
+  @override
+  String timelineMembersRemoved(String actor, String members) {
+    return '$actor удалил(а) $members';
+  }
+
+  @override
+  String timelineMemberLeft(String actor) {
+    return '$actor покинул(а) группу';
+  }
+
+  @override
+  String timelineGroupRenamed(String actor, String name) {
+    return '$actor переименовал(а) группу в $name';
+  }
+
+  @override
+  String timelineDescriptionChanged(String actor) {
+    return '$actor изменил(а) описание группы';
+  }
+
+  @override
+  String timelineImageChanged(String actor) {
+    return '$actor изменил(а) изображение группы';
+  }
+
+  @override
+  String timelineAdminsAdded(String actor, String members) {
+    return '$actor назначил(а) $members администратором';
+  }
+
+  @override
+  String timelineAdminsRemoved(String actor, String members) {
+    return '$actor снял(а) $members с роли администратора';
+  }
 }
@@ -743,4 +743,44 @@ class AppLocalizationsTr extends AppLocalizations {
 
   @override
   String get deleteAllDataError => 'Tüm veriler silinemedi. Lütfen tekrar deneyin.';
+
+  @override
+  String timelineMembersAdded(String actor, String members) {
+    return '$actor, $members üyesini ekledi';
+  }
+
+  @override
+  String timelineMembersRemoved(String actor, String members) {
+    return '$actor, $members üyesini çıkardı';
+  }
+
+  @override
+  String timelineMemberLeft(String actor) {
+    return '$actor gruptan ayrıldı';
+  }
+
+  @override
+  String timelineGroupRenamed(String actor, String name) {
+    return '$actor grubun adını $name olarak değiştirdi';
+  }
+
+  @override
+  String timelineDescriptionChanged(String actor) {
+    return '$actor grup açıklamasını değiştirdi';
+  }
+
+  @override
+  String timelineImageChanged(String actor) {
+    return '$actor grup resmini değiştirdi';
+  }
+
+  @override
+  String timelineAdminsAdded(String actor, String members) {
+    return '$actor, $members üyesini yönetici yaptı';
+  }
+
+  @override
+  String timelineAdminsRemoved(String actor, String members) {
+    return '$actor, $members üyesinin yöneticiliğini kaldırdı';
+  }
 }
@@ -35,11 +35,12 @@ class AuthNotifier extends AsyncNotifier<String?> {
     return pubkey;
   }
 
-  /// Login with a private key (nsec) or hex key.
-  Future<void> loginWithNsec(String nsec) async {
+  /// Login with a private key (nsec), a hex key or a NIP-49 encrypted key
+  /// (ncryptsec), which requires [password].
+  Future<void> loginWithNsec(String nsec, {String? password}) async {
     _logger.info('Login attempt started');
     final storage = ref.read(secureStorageProvider);
-    final account = await accounts_api.login(nsecOrHexPrivkey: nsec);
+    final account = await accounts_api.login(nsecOrHexPrivkey: nsec, password: password);
     users_api.userMetadata(pubkey: account.pubkey, blockingDataSync: false);
     await storage.write(key: _storageKey, value: account.pubkey);
     state = AsyncData(account.pubkey);
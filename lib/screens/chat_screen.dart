@@ -25,6 +25,7 @@ import 'package:whitenoise/widgets/wn_scroll_edge_effect.dart';
 import 'package:whitenoise/widgets/wn_slate.dart';
 import 'package:whitenoise/widgets/wn_slate_chat_header.dart';
 import 'package:whitenoise/widgets/wn_system_notice.dart';
+import 'package:whitenoise/widgets/wn_timeline_notice.dart';
 
 final _logger = Logger('ChatScreen');
 
@@ -45,8 +46,11 @@ class ChatScreen extends HookConsumerWidget {
       :getMessage,
       :getReversedMessageIndex,
       :isLoading,
+      :hasMoreBefore,
+      :loadOlderMessages,
       :latestMessageId,
       :latestMessagePubkey,
+      :getAuthorMetadata,
       :getReplyPreview,
     ) = useChatMessages(
       groupId,
@@ -72,19 +76,24 @@ class ChatScreen extends HookConsumerWidget {
       noticeMessage.value = null;
     }
 
+    void loadOlder() {
+      loadOlderMessages().catchError((Object e, StackTrace st) {
+        _logger.warning('Failed to load older messages', e, st);
+      });
+    }
+
     useChatScroll(
       scrollController: scrollController,
       focusNode: input.focusNode,
       latestMessageId: latestMessageId,
       isLatestMessageOwn: latestMessagePubkey == pubkey,
+      onNearTop: hasMoreBefore ? loadOlder : null,
     );
 
     Future<void> sendMessage(String message, ChatMessage? replyingTo) async {
       await messageService.sendTextMessage(
         content: message,
         replyToMessageId: replyingTo?.id,
-        replyToMessagePubkey: replyingTo?.pubkey,
-        replyToMessageKind: replyingTo?.kind,
       );
     }
 
@@ -98,19 +107,14 @@ class ChatScreen extends HookConsumerWidget {
         context,
         message: message,
         pubkey: pubkey,
-        onDelete: () => messageService.deleteTextMessage(
-          messageId: message.id,
-          messagePubkey: message.pubkey,
-        ),
+        onDelete: () => messageService.deleteTextMessage(messageId: message.id),
         onAddReaction: (emoji) => messageService.sendReaction(
           messageId: message.id,
-          messagePubkey: message.pubkey,
-          messageKind: message.kind,
           emoji: emoji,
         ),
-        onRemoveReaction: (reactionId) => messageService.deleteReaction(
-          reactionId: reactionId,
-          reactionPubkey: pubkey,
+        onRemoveReaction: (emoji) => messageService.removeReaction(
+          messageId: message.id,
+          emoji: emoji,
         ),
         onReply: (msg) => input.setReplyingTo(msg),
       );
@@ -146,6 +150,20 @@ class ChatScreen extends HookConsumerWidget {
         },
         itemBuilder: (context, index) {
           final message = getMessage(index);
+          final systemEvent = message.systemEvent;
+          if (systemEvent != null) {
+            return AutoScrollTag(
+              key: ValueKey(message.id),
+              controller: scrollController,
+              index: index,
+              child: WnTimelineNotice(
+                event: systemEvent,
+                currentUserPubkey: pubkey,
+                getAuthorMetadata: getAuthorMetadata,
+              ),
+            );
+          }
+
           final isOwnMessage = message.pubkey == pubkey;
           final replyPreview = message.isReply ? getReplyPreview(message.replyToId) : null;
 
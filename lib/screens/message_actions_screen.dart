@@ -27,7 +27,7 @@ class MessageActionsScreen extends HookWidget {
   final ChatMessage message;
   final String pubkey;
   final Future<void> Function(String emoji) onAddReaction;
-  final Future<void> Function(String reactionId) onRemoveReaction;
+  final Future<void> Function(String emoji) onRemoveReaction;
   final Future<void> Function()? onDelete;
   final void Function(ChatMessage message)? onReply;
 
@@ -36,7 +36,7 @@ class MessageActionsScreen extends HookWidget {
     required ChatMessage message,
     required String pubkey,
     required Future<void> Function(String emoji) onAddReaction,
-    required Future<void> Function(String reactionId) onRemoveReaction,
+    required Future<void> Function(String emoji) onRemoveReaction,
     Future<void> Function()? onDelete,
     void Function(ChatMessage message)? onReply,
   }) {
@@ -81,12 +81,10 @@ class MessageActionsScreen extends HookWidget {
     }
 
     final isOwnMessage = message.pubkey == pubkey;
-    final userReactionIds = Map.fromEntries(
-      message.reactions.userReactions
-          .where((r) => r.user == pubkey)
-          .map((r) => MapEntry(r.emoji, r.reactionId)),
-    );
-    final selectedEmojis = userReactionIds.keys.toSet();
+    final selectedEmojis = message.reactions.userReactions
+        .where((r) => r.user == pubkey)
+        .map((r) => r.emoji)
+        .toSet();
 
     Future<void> handleDelete() async {
       try {
@@ -100,10 +98,10 @@ class MessageActionsScreen extends HookWidget {
     }
 
     Future<void> handleReaction(String emoji) async {
-      final reactionId = userReactionIds[emoji];
+      final hasReacted = selectedEmojis.contains(emoji);
       try {
-        if (reactionId != null) {
-          await onRemoveReaction(reactionId);
+        if (hasReacted) {
+          await onRemoveReaction(emoji);
         } else {
           await onAddReaction(emoji);
         }
@@ -111,7 +109,7 @@ class MessageActionsScreen extends HookWidget {
       } catch (_) {
         if (context.mounted) {
           showNotice(
-            reactionId != null
+            hasReacted
                 ? context.l10n.failedToRemoveReaction
                 : context.l10n.failedToSendReaction,
           );
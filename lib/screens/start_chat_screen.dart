@@ -65,7 +65,7 @@ class StartChatScreen extends HookConsumerWidget {
       isStartingChat.value = true;
 
       try {
-        final group = await groups_api.createGroup(
+        final result = await groups_api.createGroup(
           creatorPubkey: accountPubkey,
           memberPubkeys: [userPubkey],
           adminPubkeys: [accountPubkey],
@@ -75,7 +75,7 @@ class StartChatScreen extends HookConsumerWidget {
         );
 
         if (context.mounted) {
-          Routes.goToChat(context, group.mlsGroupId);
+          Routes.goToChat(context, result.group.mlsGroupId);
         }
       } catch (e) {
         _logger.severe('Failed to start chat: $e');
@@ -1,7 +1,6 @@
 import 'package:logging/logging.dart';
 import 'package:whitenoise/constants/nostr_event_kinds.dart';
 import 'package:whitenoise/src/rust/api/messages.dart' as messages_api;
-import 'package:whitenoise/src/rust/api/utils.dart' as utils_api;
 
 final _logger = Logger('MessageService');
 
@@ -14,130 +13,77 @@ class MessageService {
   Future<void> sendTextMessage({
     required String content,
     String? replyToMessageId,
-    String? replyToMessagePubkey,
-    int? replyToMessageKind,
   }) async {
     _logger.info('Sending text message to group $groupId');
 
-    final tags =
-        (replyToMessageId != null && replyToMessagePubkey != null && replyToMessageKind != null)
-        ? await _eventReferenceTags(
-            eventId: replyToMessageId,
-            eventPubkey: replyToMessagePubkey,
-            eventKind: replyToMessageKind,
-          )
-        : null;
-
-    await messages_api.sendMessageToGroup(
-      pubkey: pubkey,
-      groupId: groupId,
-      message: content,
-      kind: NostrEventKinds.chatMessage,
-      tags: tags,
-    );
+    if (replyToMessageId != null) {
+      await messages_api.sendReply(
+        pubkey: pubkey,
+        groupId: groupId,
+        messageId: replyToMessageId,
+        message: content,
+      );
+    } else {
+      await messages_api.sendMessageToGroup(
+        pubkey: pubkey,
+        groupId: groupId,
+        message: content,
+        kind: NostrEventKinds.chatMessage,
+      );
+    }
     _logger.info('Message sent successfully');
   }
 
   Future<void> sendReaction({
     required String messageId,
-    required String messagePubkey,
-    required int messageKind,
     required String emoji,
   }) async {
-    final tags = await _eventReferenceTags(
-      eventId: messageId,
-      eventPubkey: messagePubkey,
-      eventKind: messageKind,
-    );
-
     _logger.info('Sending reaction to message $messageId');
-    await messages_api.sendMessageToGroup(
+    await messages_api.sendReaction(
       pubkey: pubkey,
       groupId: groupId,
-      message: emoji,
-      kind: NostrEventKinds.reaction,
-      tags: tags,
+      messageId: messageId,
+      emoji: emoji,
     );
     _logger.info('Reaction sent successfully');
   }
 
-  Future<void> toggleReaction({
-    required messages_api.ChatMessage message,
-    required String emoji,
-  }) async {
-    final existingReaction = message.reactions.userReactions
-        .where((r) => r.user == pubkey && r.emoji == emoji)
-        .firstOrNull;
-
-    if (existingReaction != null) {
-      await deleteReaction(
-        reactionId: existingReaction.reactionId,
-        reactionPubkey: pubkey,
-      );
-    } else {
-      await sendReaction(
-        messageId: message.id,
-        messagePubkey: message.pubkey,
-        messageKind: message.kind,
-        emoji: emoji,
-      );
-    }
-  }
-
-  Future<void> deleteTextMessage({
+  Future<void> removeReaction({
     required String messageId,
-    required String messagePubkey,
+    required String emoji,
   }) async {
-    await _deleteEvent(
-      eventId: messageId,
-      eventPubkey: messagePubkey,
-      eventKind: NostrEventKinds.chatMessage,
+    _logger.info('Removing reaction from message $messageId');
+    await messages_api.removeReaction(
+      pubkey: pubkey,
+      groupId: groupId,
+      messageId: messageId,
+      emoji: emoji,
     );
+    _logger.info('Reaction removed successfully');
   }
 
-  Future<void> deleteReaction({
-    required String reactionId,
-    required String reactionPubkey,
+  Future<void> toggleReaction({
+    required messages_api.ChatMessage message,
+    required String emoji,
   }) async {
-    await _deleteEvent(
-      eventId: reactionId,
-      eventPubkey: reactionPubkey,
-      eventKind: NostrEventKinds.reaction,
+    final hasReacted = message.reactions.userReactions.any(
+      (r) => r.user == pubkey && r.emoji == emoji,
     );
-  }
 
-  Future<void> _deleteEvent({
-    required String eventId,
-    required String eventPubkey,
-    required int eventKind,
-  }) async {
-    _logger.info('Building deletion tags for event $eventId');
-    final tags = await _eventReferenceTags(
-      eventId: eventId,
-      eventPubkey: eventPubkey,
-      eventKind: eventKind,
-    );
+    if (hasReacted) {
+      await removeReaction(messageId: message.id, emoji: emoji);
+    } else {
+      await sendReaction(messageId: message.id, emoji: emoji);
+    }
+  }
 
-    _logger.info('Deleting event $eventId');
-    await messages_api.sendMessageToGroup(
+  Future<void> deleteTextMessage({required String messageId}) async {
+    _logger.info('Deleting message $messageId');
+    await messages_api.deleteMessage(
       pubkey: pubkey,
       groupId: groupId,
-      message: '',
-      tags: tags,
-      kind: NostrEventKinds.deletion,
+      messageId: messageId,
     );
-    _logger.info('Event $eventId deleted successfully');
-  }
-
-  Future<List<messages_api.Tag>> _eventReferenceTags({
-    required String eventId,
-    required String eventPubkey,
-    required int eventKind,
-  }) {
-    return Future.wait([
-      utils_api.tagFromVec(vec: ['e', eventId]),
-      utils_api.tagFromVec(vec: ['p', eventPubkey, '']),
-      utils_api.tagFromVec(vec: ['k', eventKind.toString()]),
-    ]);
+    _logger.info('Message $messageId deleted successfully');
   }
 }
@@ -0,0 +1,56 @@
+import 'package:flutter/material.dart';
+import 'package:flutter_screenutil/flutter_screenutil.dart';
+import 'package:whitenoise/l10n/l10n.dart';
+import 'package:whitenoise/src/rust/api/messages.dart';
+import 'package:whitenoise/src/rust/api/metadata.dart';
+import 'package:whitenoise/theme.dart';
+import 'package:whitenoise/utils/metadata.dart';
+
+class WnTimelineNotice extends StatelessWidget {
+  const WnTimelineNotice({
+    super.key,
+    required this.event,
+    required this.getAuthorMetadata,
+    this.currentUserPubkey,
+  });
+
+  final SystemEvent event;
+  final FlutterMetadata? Function(String pubkey) getAuthorMetadata;
+  final String? currentUserPubkey;
+
+  @override
+  Widget build(BuildContext context) {
+    final l10n = context.l10n;
+
+    String name(String pubkey) {
+      if (pubkey == currentUserPubkey) return l10n.you;
+      return presentName(getAuthorMetadata(pubkey)) ?? l10n.unknownUser;
+    }
+
+    String names(List<String> pubkeys) => pubkeys.map(name).join(', ');
+
+    final text = event.when(
+      membersAdded: (actor, members) => l10n.timelineMembersAdded(name(actor), names(members)),
+      membersRemoved: (actor, members) => l10n.timelineMembersRemoved(name(actor), names(members)),
+      memberLeft: (actor) => l10n.timelineMemberLeft(name(actor)),
+      groupRenamed: (actor, groupName) => l10n.timelineGroupRenamed(name(actor), groupName),
+      descriptionChanged: (actor, _) => l10n.timelineDescriptionChanged(name(actor)),
+      imageChanged: (actor) => l10n.timelineImageChanged(name(actor)),
+      adminsChanged: (actor, added, removed) => [
+        if (added.isNotEmpty) l10n.timelineAdminsAdded(name(actor), names(added)),
+        if (removed.isNotEmpty) l10n.timelineAdminsRemoved(name(actor), names(removed)),
+      ].join('\n'),
+    );
+
+    return Padding(
+      padding: EdgeInsets.symmetric(horizontal: 24.w, vertical: 8.h),
+      child: Text(
+        text,
+        textAlign: TextAlign.center,
+        style: context.typographyScaled.medium12.copyWith(
+          color: context.colors.backgroundContentTertiary,
+        ),
+      ),
+    );
+  }
+}
@@ -1,10 +1,14 @@
-use crate::api::{error::ApiError, utils::group_id_to_string};
+use crate::api::{chat_list::NotificationLevel, error::ApiError, utils::group_id_to_string};
+use chrono::{DateTime, Utc};
 use flutter_rust_bridge::frb;
 use mdk_core::prelude::GroupId;
 use nostr_sdk::prelude::*;
 use whitenoise::AccountGroup as WhitenoiseAccountGroup;
 use whitenoise::Whitenoise;
 
+/// Largest group (in members) that read receipts are published to
+const READ_RECEIPTS_MAX_GROUP_SIZE: usize = 10;
+
 /// Represents the relationship between an account and an MLS group.
 ///
 /// This struct tracks whether a user has accepted or declined a group invite.
@@ -30,6 +34,12 @@ pub struct AccountGroup {
     /// - `None` = not pinned (appears after pinned chats)
     /// - `Some(n)` = pinned, lower values appear first
     pub pin_order: Option<i64>,
+    /// Notification preference for this group
+    pub notification_level: NotificationLevel,
+    /// When a temporary mute ends (`None` = not muted or muted until changed)
+    pub muted_until: Option<DateTime<Utc>>,
+    /// When the account last rotated its leaf key in this group
+    pub last_key_rotation_at: Option<DateTime<Utc>>,
     pub created_at: i64,
     pub updated_at: i64,
 }
@@ -44,6 +54,9 @@ impl From<WhitenoiseAccountGroup> for AccountGroup {
             welcomer_pubkey: ag.welcomer_pubkey.map(|pk| pk.to_hex()),
             last_read_message_id: ag.last_read_message_id.map(|id| id.to_hex()),
             pin_order: ag.pin_order,
+            notification_level: ag.notification_level.into(),
+            muted_until: ag.muted_until,
+            last_key_rotation_at: ag.last_key_rotation_at,
             created_at: ag.created_at.timestamp_millis(),
             updated_at: ag.updated_at.timestamp_millis(),
         }
@@ -60,6 +73,9 @@ impl From<&WhitenoiseAccountGroup> for AccountGroup {
             welcomer_pubkey: ag.welcomer_pubkey.map(|pk| pk.to_hex()),
             last_read_message_id: ag.last_read_message_id.map(|id| id.to_hex()),
             pin_order: ag.pin_order,
+            notification_level: ag.notification_level.clone().into(),
+            muted_until: ag.muted_until,
+            last_key_rotation_at: ag.last_key_rotation_at,
             created_at: ag.created_at.timestamp_millis(),
             updated_at: ag.updated_at.timestamp_millis(),
         }
@@ -114,6 +130,10 @@ pub async fn decline_account_group(
 ///
 /// Updates the `last_read_message_id` for the account-group pair containing
 /// the specified message. This is used to compute unread counts in the chat list.
+///
+/// When read receipts are enabled in the account settings and the group has at
+/// most `READ_RECEIPTS_MAX_GROUP_SIZE` members, a read receipt is also
+/// published into the group.
 #[frb]
 pub async fn mark_message_read(
     account_pubkey: String,
@@ -125,5 +145,25 @@ pub async fn mark_message_read(
     let event_id = EventId::from_hex(&message_id)?;
 
     let updated = whitenoise.mark_message_read(&account, &event_id).await?;
+
+    // Best effort: the local read marker is already stored, failing to look up
+    // the settings or members, or to send the receipt, only means other
+    // members see it later or not at all
+    let receipts_enabled = whitenoise
+        .account_settings(&account)
+        .await
+        .is_ok_and(|settings| settings.read_receipts_enabled);
+    if receipts_enabled {
+        let small_group = whitenoise
+            .group_members(&account, &updated.mls_group_id)
+            .await
+            .is_ok_and(|members| members.len() <= READ_RECEIPTS_MAX_GROUP_SIZE);
+        if small_group {
+            let _ = whitenoise
+                .send_read_receipt(&account, &updated.mls_group_id, &event_id)
+                .await;
+        }
+    }
+
     Ok(updated.into())
 }
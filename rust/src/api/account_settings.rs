@@ -1,4 +1,5 @@
 use crate::api::error::ApiError;
+use crate::api::key_rotation::KeyRotationPolicy;
 use chrono::{DateTime, Utc};
 use flutter_rust_bridge::frb;
 use nostr_sdk::PublicKey;
@@ -9,6 +10,10 @@ use whitenoise::{AccountSettings as WhitenoiseAccountSettings, Whitenoise};
 #[derive(Debug, Clone)]
 pub struct AccountSettings {
     pub notifications_enabled: bool,
+    /// Whether read receipts are published to small groups and DMs
+    pub read_receipts_enabled: bool,
+    /// When the account's leaf key is rotated automatically in its groups
+    pub key_rotation_policy: KeyRotationPolicy,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
@@ -17,6 +22,8 @@ impl From<WhitenoiseAccountSettings> for AccountSettings {
     fn from(settings: WhitenoiseAccountSettings) -> Self {
         Self {
             notifications_enabled: settings.notifications_enabled,
+            read_receipts_enabled: settings.read_receipts_enabled,
+            key_rotation_policy: settings.key_rotation_policy.into(),
             created_at: settings.created_at,
             updated_at: settings.updated_at,
         }
@@ -45,3 +52,31 @@ pub async fn update_notifications_enabled(
         .await?;
     Ok(settings.into())
 }
+
+#[frb]
+pub async fn update_read_receipts_enabled(
+    pubkey: String,
+    enabled: bool,
+) -> Result<AccountSettings, ApiError> {
+    let whitenoise = Whitenoise::get_instance()?;
+    let pubkey = PublicKey::parse(&pubkey)?;
+    let account = whitenoise.find_account_by_pubkey(&pubkey).await?;
+    let settings = whitenoise
+        .update_read_receipts_enabled(&account, enabled)
+        .await?;
+    Ok(settings.into())
+}
+
+#[frb]
+pub async fn update_key_rotation_policy(
+    pubkey: String,
+    policy: KeyRotationPolicy,
+) -> Result<AccountSettings, ApiError> {
+    let whitenoise = Whitenoise::get_instance()?;
+    let pubkey = PublicKey::parse(&pubkey)?;
+    let account = whitenoise.find_account_by_pubkey(&pubkey).await?;
+    let settings = whitenoise
+        .update_key_rotation_policy(&account, policy.try_into()?)
+        .await?;
+    Ok(settings.into())
+}
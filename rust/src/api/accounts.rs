@@ -1,10 +1,15 @@
-use crate::api::{error::ApiError, metadata::FlutterMetadata, relays::Relay, users::User};
-use chrono::{DateTime, TimeZone, Utc};
+use crate::api::{
+    chat_list::ChatSummary, error::ApiError, metadata::FlutterMetadata, relays::Relay, users::User,
+    utils::timestamp_to_datetime,
+};
+use crate::frb_generated::StreamSink;
+use chrono::{DateTime, Utc};
 use flutter_rust_bridge::frb;
 use nostr_sdk::prelude::*;
 use whitenoise::{
-    Account as WhitenoiseAccount, AccountType as WhitenoiseAccountType, ImageType, RelayType,
-    Whitenoise,
+    Account as WhitenoiseAccount, AccountType as WhitenoiseAccountType,
+    AccountUpdate as WhitenoiseAccountUpdate,
+    AccountUpdateTrigger as WhitenoiseAccountUpdateTrigger, ImageType, RelayType, Whitenoise,
 };
 
 /// The type of account authentication.
@@ -49,6 +54,114 @@ impl From<WhitenoiseAccount> for Account {
     }
 }
 
+/// What triggered an account update in the stream.
+#[frb]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AccountUpdateTrigger {
+    /// An account was created or logged in
+    AccountAdded,
+    /// An account was logged out
+    AccountRemoved,
+    /// The account or its metadata changed
+    AccountUpdated,
+    /// The account started or finished syncing with its relays
+    SyncStateChanged,
+}
+
+impl From<WhitenoiseAccountUpdateTrigger> for AccountUpdateTrigger {
+    fn from(trigger: WhitenoiseAccountUpdateTrigger) -> Self {
+        match trigger {
+            WhitenoiseAccountUpdateTrigger::AccountAdded => Self::AccountAdded,
+            WhitenoiseAccountUpdateTrigger::AccountRemoved => Self::AccountRemoved,
+            WhitenoiseAccountUpdateTrigger::AccountUpdated => Self::AccountUpdated,
+            WhitenoiseAccountUpdateTrigger::SyncStateChanged => Self::SyncStateChanged,
+        }
+    }
+}
+
+/// A real-time update for an account.
+///
+/// Contains the trigger indicating what changed and the account state. For
+/// `AccountRemoved` this is the last state before logout.
+#[frb(non_opaque)]
+#[derive(Debug, Clone)]
+pub struct AccountUpdate {
+    pub trigger: AccountUpdateTrigger,
+    pub account: Account,
+}
+
+impl From<WhitenoiseAccountUpdate> for AccountUpdate {
+    fn from(update: WhitenoiseAccountUpdate) -> Self {
+        Self {
+            trigger: update.trigger.into(),
+            account: update.account.into(),
+        }
+    }
+}
+
+/// Stream item emitted by `subscribe_to_accounts`.
+///
+/// The first item is always `InitialSnapshot` containing all accounts.
+/// Subsequent items are `Update` containing real-time changes.
+#[frb]
+#[derive(Debug, Clone)]
+pub enum AccountStreamItem {
+    /// All accounts at subscription time
+    InitialSnapshot { accounts: Vec<Account> },
+    /// Real-time update of an account
+    Update { update: AccountUpdate },
+}
+
+/// Bech32 prefix of NIP-49 encrypted private keys
+const NCRYPTSEC_PREFIX: &str = "ncryptsec1";
+/// Smallest scrypt work factor accepted for exports
+const MIN_NCRYPTSEC_LOG_N: u8 = 16;
+/// Largest scrypt work factor accepted for exports (1 GiB of memory)
+const MAX_NCRYPTSEC_LOG_N: u8 = 20;
+
+fn encrypt_ncryptsec(
+    secret_key: &SecretKey,
+    password: &str,
+    log_n: u8,
+) -> Result<String, ApiError> {
+    // The key was available in plaintext to the app, so its history is unknown
+    let encrypted = EncryptedSecretKey::new(secret_key, password, log_n, KeySecurity::Unknown)?;
+    encrypted
+        .to_bech32()
+        .map_err(|e| ApiError::InvalidEncryptedKey {
+            message: e.to_string(),
+        })
+}
+
+fn decrypt_ncryptsec(ncryptsec: &str, password: &str) -> Result<SecretKey, ApiError> {
+    let encrypted =
+        EncryptedSecretKey::from_bech32(ncryptsec).map_err(|e| ApiError::InvalidEncryptedKey {
+            message: e.to_string(),
+        })?;
+    Ok(encrypted.decrypt(password)?)
+}
+
+/// Unread totals of an account, for the account switcher badges.
+#[frb(non_opaque)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AccountUnreadCount {
+    pub pubkey: String,
+    /// Number of unread messages across the account's chats
+    pub unread_count: u64,
+    /// Number of chats with at least one unread message
+    pub unread_chat_count: u64,
+}
+
+/// Sums the unread messages of the chats shown in the active chat list.
+fn account_unread_count(pubkey: String, chats: &[ChatSummary]) -> AccountUnreadCount {
+    let active = chats.iter().filter(|chat| !chat.archived);
+    AccountUnreadCount {
+        pubkey,
+        unread_count: active.clone().map(|chat| chat.unread_count).sum(),
+        unread_chat_count: active.filter(|chat| chat.unread_count > 0).count() as u64,
+    }
+}
+
 #[frb(non_opaque)]
 #[derive(Debug, Clone)]
 pub struct FlutterEvent {
@@ -65,12 +178,7 @@ impl From<Event> for FlutterEvent {
         Self {
             id: event.id.to_hex(),
             pubkey: event.pubkey.to_hex(),
-            created_at: {
-                let ts = i64::try_from(event.created_at.as_secs()).unwrap_or(0);
-                Utc.timestamp_opt(ts, 0)
-                    .single()
-                    .unwrap_or_else(|| Utc.timestamp_opt(0, 0).single().unwrap())
-            },
+            created_at: timestamp_to_datetime(event.created_at),
             kind: event.kind.as_u16(),
             tags: event
                 .tags
@@ -89,6 +197,75 @@ pub async fn get_accounts() -> Result<Vec<Account>, ApiError> {
     Ok(accounts.into_iter().map(|a| a.into()).collect())
 }
 
+/// Subscribe to account lifecycle events.
+///
+/// The stream first emits an `InitialSnapshot` with all accounts, then emits
+/// `Update` items on login, logout, identity creation, account changes and
+/// sync state changes.
+#[frb]
+pub async fn subscribe_to_accounts(sink: StreamSink<AccountStreamItem>) -> Result<(), ApiError> {
+    let whitenoise = Whitenoise::get_instance()?;
+    let subscription = whitenoise.subscribe_to_accounts().await?;
+
+    // Emit initial snapshot first
+    let accounts: Vec<Account> = subscription
+        .initial_accounts
+        .into_iter()
+        .map(|a| a.into())
+        .collect();
+
+    if sink
+        .add(AccountStreamItem::InitialSnapshot { accounts })
+        .is_err()
+    {
+        return Ok(()); // Sink closed, exit gracefully
+    }
+
+    // Stream real-time updates
+    let mut rx = subscription.updates;
+    loop {
+        match rx.recv().await {
+            Ok(update) => {
+                let item = AccountStreamItem::Update {
+                    update: update.into(),
+                };
+                if sink.add(item).is_err() {
+                    break; // Sink closed
+                }
+            }
+            Err(tokio::sync::broadcast::error::RecvError::Lagged(_)) => {
+                // Slow consumer missed some updates - safe to continue since
+                // each update contains the complete account state
+                continue;
+            }
+            Err(tokio::sync::broadcast::error::RecvError::Closed) => {
+                break; // Channel closed
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Returns the unread totals of every account in one call.
+///
+/// Archived chats are not counted, matching the active chat list.
+#[frb]
+pub async fn unread_counts_by_account() -> Result<Vec<AccountUnreadCount>, ApiError> {
+    let whitenoise = Whitenoise::get_instance()?;
+    let mut counts = Vec::new();
+    for account in whitenoise.all_accounts().await? {
+        let chats: Vec<ChatSummary> = whitenoise
+            .get_chat_list(&account)
+            .await?
+            .into_iter()
+            .map(ChatSummary::from)
+            .collect();
+        counts.push(account_unread_count(account.pubkey.to_hex(), &chats));
+    }
+    Ok(counts)
+}
+
 #[frb]
 pub async fn get_account(pubkey: String) -> Result<Account, ApiError> {
     let whitenoise = Whitenoise::get_instance()?;
@@ -104,10 +281,34 @@ pub async fn create_identity() -> Result<Account, ApiError> {
     Ok(account.into())
 }
 
+/// Logs in with a private key.
+///
+/// Accepts an nsec, a hex private key or a NIP-49 `ncryptsec1...` encrypted
+/// key. `password` is required for ncryptsec keys and ignored otherwise.
 #[frb]
-pub async fn login(nsec_or_hex_privkey: String) -> Result<Account, ApiError> {
+pub async fn login(
+    nsec_or_hex_privkey: String,
+    password: Option<String>,
+) -> Result<Account, ApiError> {
     let whitenoise = Whitenoise::get_instance()?;
-    let account = whitenoise.login(nsec_or_hex_privkey).await?;
+    let private_key = nsec_or_hex_privkey.trim().to_string();
+    let private_key = if private_key.starts_with(NCRYPTSEC_PREFIX) {
+        let password = password
+            .filter(|password| !password.is_empty())
+            .ok_or_else(|| ApiError::PasswordRequired {
+                message: "A password is required for encrypted keys".to_string(),
+            })?;
+        let secret_key =
+            tokio::task::spawn_blocking(move || decrypt_ncryptsec(&private_key, &password))
+                .await
+                .map_err(|e| ApiError::Other {
+                    message: e.to_string(),
+                })??;
+        secret_key.to_secret_hex()
+    } else {
+        private_key
+    };
+    let account = whitenoise.login(private_key).await?;
     Ok(account.into())
 }
 
@@ -129,6 +330,40 @@ pub async fn export_account_nsec(pubkey: String) -> Result<String, ApiError> {
         .map_err(ApiError::from)
 }
 
+/// Exports the account's private key encrypted with `password` (NIP-49).
+///
+/// `log_n` sets the scrypt work factor, between 16 (64 MiB, fast) and 20
+/// (1 GiB, slow). Higher values make brute forcing the password harder.
+#[frb]
+pub async fn export_account_ncryptsec(
+    pubkey: String,
+    password: String,
+    log_n: u8,
+) -> Result<String, ApiError> {
+    if !(MIN_NCRYPTSEC_LOG_N..=MAX_NCRYPTSEC_LOG_N).contains(&log_n) {
+        return Err(ApiError::InvalidEncryptedKey {
+            message: format!(
+                "log_n must be between {MIN_NCRYPTSEC_LOG_N} and {MAX_NCRYPTSEC_LOG_N}"
+            ),
+        });
+    }
+    if password.is_empty() {
+        return Err(ApiError::PasswordRequired {
+            message: "Password must not be empty".to_string(),
+        });
+    }
+    let whitenoise = Whitenoise::get_instance()?;
+    let pubkey = PublicKey::parse(&pubkey)?;
+    let account = whitenoise.find_account_by_pubkey(&pubkey).await?;
+    let nsec = whitenoise.export_account_nsec(&account).await?;
+    let secret_key = SecretKey::parse(&nsec)?;
+    tokio::task::spawn_blocking(move || encrypt_ncryptsec(&secret_key, &password, log_n))
+        .await
+        .map_err(|e| ApiError::Other {
+            message: e.to_string(),
+        })?
+}
+
 #[frb]
 pub async fn update_account_metadata(
     pubkey: String,
@@ -316,3 +551,73 @@ pub async fn is_following_user(
         .await
         .map_err(ApiError::from)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn chat(unread_count: u64, archived: bool) -> ChatSummary {
+        ChatSummary {
+            unread_count,
+            archived,
+            ..ChatSummary::for_test("")
+        }
+    }
+
+    #[test]
+    fn test_account_update_trigger_conversion_account_added() {
+        let trigger: AccountUpdateTrigger = WhitenoiseAccountUpdateTrigger::AccountAdded.into();
+        assert_eq!(trigger, AccountUpdateTrigger::AccountAdded);
+    }
+
+    #[test]
+    fn test_account_update_trigger_conversion_sync_state_changed() {
+        let trigger: AccountUpdateTrigger = WhitenoiseAccountUpdateTrigger::SyncStateChanged.into();
+        assert_eq!(trigger, AccountUpdateTrigger::SyncStateChanged);
+    }
+
+    #[test]
+    fn test_ncryptsec_round_trip() {
+        let secret_key = Keys::generate().secret_key().clone();
+        let ncryptsec = encrypt_ncryptsec(&secret_key, "correct horse", 16).unwrap();
+        assert!(ncryptsec.starts_with(NCRYPTSEC_PREFIX));
+        let decrypted = decrypt_ncryptsec(&ncryptsec, "correct horse").unwrap();
+        assert_eq!(decrypted, secret_key);
+    }
+
+    #[test]
+    fn test_ncryptsec_wrong_password() {
+        let secret_key = Keys::generate().secret_key().clone();
+        let ncryptsec = encrypt_ncryptsec(&secret_key, "correct horse", 16).unwrap();
+        assert!(matches!(
+            decrypt_ncryptsec(&ncryptsec, "battery staple"),
+            Err(ApiError::WrongPassword { .. })
+        ));
+    }
+
+    #[test]
+    fn test_ncryptsec_rejects_malformed_input() {
+        assert!(matches!(
+            decrypt_ncryptsec("ncryptsec1notvalid", "password"),
+            Err(ApiError::InvalidEncryptedKey { .. })
+        ));
+    }
+
+    #[test]
+    fn test_account_unread_count_skips_archived_chats() {
+        let chats = vec![
+            chat(3, false),
+            chat(0, false),
+            chat(2, false),
+            chat(5, true),
+        ];
+        assert_eq!(
+            account_unread_count("pk".to_string(), &chats),
+            AccountUnreadCount {
+                pubkey: "pk".to_string(),
+                unread_count: 5,
+                unread_chat_count: 2,
+            }
+        );
+    }
+}
@@ -9,9 +9,42 @@ use nostr_sdk::PublicKey;
 use whitenoise::whitenoise::chat_list::ChatListItem as WhitenoiseChatListItem;
 use whitenoise::{
     ChatListUpdate as WhitenoiseChatListUpdate,
-    ChatListUpdateTrigger as WhitenoiseChatListUpdateTrigger, Whitenoise,
+    ChatListUpdateTrigger as WhitenoiseChatListUpdateTrigger,
+    NotificationLevel as WhitenoiseNotificationLevel, Whitenoise,
 };
 
+/// Per-chat notification preference.
+#[frb]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NotificationLevel {
+    /// Notify for every new message
+    All,
+    /// Notify only for messages mentioning the account
+    MentionsOnly,
+    /// No notifications, until `muted_until` if set
+    Muted,
+}
+
+impl From<WhitenoiseNotificationLevel> for NotificationLevel {
+    fn from(level: WhitenoiseNotificationLevel) -> Self {
+        match level {
+            WhitenoiseNotificationLevel::All => Self::All,
+            WhitenoiseNotificationLevel::MentionsOnly => Self::MentionsOnly,
+            WhitenoiseNotificationLevel::Muted => Self::Muted,
+        }
+    }
+}
+
+impl From<NotificationLevel> for WhitenoiseNotificationLevel {
+    fn from(level: NotificationLevel) -> Self {
+        match level {
+            NotificationLevel::All => Self::All,
+            NotificationLevel::MentionsOnly => Self::MentionsOnly,
+            NotificationLevel::Muted => Self::Muted,
+        }
+    }
+}
+
 #[frb(non_opaque)]
 #[derive(Debug, Clone)]
 pub struct ChatSummary {
@@ -45,6 +78,13 @@ pub struct ChatSummary {
     /// For DMs: the public key (hex) of the other participant.
     /// `None` for Group chats.
     pub dm_peer_pubkey: Option<String>,
+    /// Notification preference for this chat
+    pub notification_level: NotificationLevel,
+    /// When a temporary mute ends.
+    /// `None` with `NotificationLevel::Muted` = muted until changed.
+    pub muted_until: Option<DateTime<Utc>>,
+    /// Whether the chat is archived (hidden from the active chat list)
+    pub archived: bool,
 }
 
 impl From<WhitenoiseChatListItem> for ChatSummary {
@@ -64,6 +104,33 @@ impl From<WhitenoiseChatListItem> for ChatSummary {
             unread_count: item.unread_count as u64,
             pin_order: item.pin_order,
             dm_peer_pubkey: item.dm_peer_pubkey.map(|pk| pk.to_hex()),
+            notification_level: item.notification_level.into(),
+            muted_until: item.muted_until,
+            archived: item.archived,
+        }
+    }
+}
+
+#[cfg(test)]
+impl ChatSummary {
+    /// Accepted, unarchived group chat without messages, created at the Unix epoch.
+    pub(crate) fn for_test(mls_group_id: &str) -> Self {
+        Self {
+            mls_group_id: mls_group_id.to_string(),
+            name: None,
+            group_type: GroupType::Group,
+            created_at: DateTime::from_timestamp(0, 0).unwrap(),
+            group_image_path: None,
+            group_image_url: None,
+            last_message: None,
+            pending_confirmation: false,
+            welcomer_pubkey: None,
+            unread_count: 0,
+            pin_order: None,
+            dm_peer_pubkey: None,
+            notification_level: NotificationLevel::All,
+            muted_until: None,
+            archived: false,
         }
     }
 }
@@ -78,6 +145,18 @@ pub enum ChatListUpdateTrigger {
     NewLastMessage,
     /// The last message in a chat was deleted
     LastMessageDeleted,
+    /// The chat was archived
+    ChatArchived,
+    /// The chat was unarchived, manually or by a new message
+    ChatUnarchived,
+    /// The account left the group; the chat should be removed from the list
+    GroupLeft,
+    /// The group name, description or image changed
+    GroupMetadataChanged,
+    /// Members were added to or removed from the group
+    GroupMembersChanged,
+    /// The group admin list changed
+    GroupAdminsChanged,
 }
 
 impl From<WhitenoiseChatListUpdateTrigger> for ChatListUpdateTrigger {
@@ -86,6 +165,12 @@ impl From<WhitenoiseChatListUpdateTrigger> for ChatListUpdateTrigger {
             WhitenoiseChatListUpdateTrigger::NewGroup => Self::NewGroup,
             WhitenoiseChatListUpdateTrigger::NewLastMessage => Self::NewLastMessage,
             WhitenoiseChatListUpdateTrigger::LastMessageDeleted => Self::LastMessageDeleted,
+            WhitenoiseChatListUpdateTrigger::ChatArchived => Self::ChatArchived,
+            WhitenoiseChatListUpdateTrigger::ChatUnarchived => Self::ChatUnarchived,
+            WhitenoiseChatListUpdateTrigger::GroupLeft => Self::GroupLeft,
+            WhitenoiseChatListUpdateTrigger::GroupMetadataChanged => Self::GroupMetadataChanged,
+            WhitenoiseChatListUpdateTrigger::GroupMembersChanged => Self::GroupMembersChanged,
+            WhitenoiseChatListUpdateTrigger::GroupAdminsChanged => Self::GroupAdminsChanged,
         }
     }
 }
@@ -110,6 +195,28 @@ impl From<WhitenoiseChatListUpdate> for ChatListUpdate {
     }
 }
 
+/// Which chats to include in the chat list.
+#[frb]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ChatListFilter {
+    /// Chats that are not archived
+    Active,
+    /// Archived chats only
+    Archived,
+    /// Every chat
+    All,
+}
+
+impl ChatListFilter {
+    fn matches(&self, item: &ChatSummary) -> bool {
+        match self {
+            ChatListFilter::Active => !item.archived,
+            ChatListFilter::Archived => item.archived,
+            ChatListFilter::All => true,
+        }
+    }
+}
+
 /// Stream item emitted by `subscribe_to_chat_list`.
 ///
 /// The first item is always `InitialSnapshot` containing all current chats.
@@ -149,19 +256,104 @@ pub async fn set_chat_pin_order(
     Ok(())
 }
 
+/// Sets the notification level for a chat.
+///
+/// - `NotificationLevel::All` / `MentionsOnly` = `muted_until` must be `None`
+/// - `NotificationLevel::Muted` with `muted_until = None` = muted until changed
+/// - `NotificationLevel::Muted` with `muted_until = Some(t)` = muted until `t`,
+///   then notifications fall back to `All`
+#[frb]
+pub async fn set_chat_notification_level(
+    account_pubkey: String,
+    mls_group_id: String,
+    notification_level: NotificationLevel,
+    muted_until: Option<DateTime<Utc>>,
+) -> Result<(), ApiError> {
+    if muted_until.is_some() && notification_level != NotificationLevel::Muted {
+        return Err(ApiError::Other {
+            message: "muted_until can only be set when muting a chat".to_string(),
+        });
+    }
+    if muted_until.is_some_and(|until| until <= Utc::now()) {
+        return Err(ApiError::Other {
+            message: "muted_until must be in the future".to_string(),
+        });
+    }
+
+    let whitenoise = Whitenoise::get_instance()?;
+    let pubkey = PublicKey::parse(&account_pubkey)?;
+    let group_id_bytes = hex::decode(&mls_group_id)?;
+    let group_id = mdk_core::prelude::GroupId::from_slice(&group_id_bytes);
+    let account = whitenoise.find_account_by_pubkey(&pubkey).await?;
+
+    whitenoise
+        .set_chat_notification_level(&account, &group_id, notification_level.into(), muted_until)
+        .await?;
+
+    Ok(())
+}
+
+/// Archives a chat, hiding it from the active chat list.
+///
+/// When `unarchive_on_new_message` is true, the next new message in the chat
+/// moves it back to the active chat list.
+#[frb]
+pub async fn archive_chat(
+    account_pubkey: String,
+    mls_group_id: String,
+    unarchive_on_new_message: bool,
+) -> Result<(), ApiError> {
+    let whitenoise = Whitenoise::get_instance()?;
+    let pubkey = PublicKey::parse(&account_pubkey)?;
+    let group_id_bytes = hex::decode(&mls_group_id)?;
+    let group_id = mdk_core::prelude::GroupId::from_slice(&group_id_bytes);
+    let account = whitenoise.find_account_by_pubkey(&pubkey).await?;
+
+    whitenoise
+        .set_chat_archived(&account, &group_id, true, unarchive_on_new_message)
+        .await?;
+
+    Ok(())
+}
+
+/// Moves an archived chat back to the active chat list.
+#[frb]
+pub async fn unarchive_chat(account_pubkey: String, mls_group_id: String) -> Result<(), ApiError> {
+    let whitenoise = Whitenoise::get_instance()?;
+    let pubkey = PublicKey::parse(&account_pubkey)?;
+    let group_id_bytes = hex::decode(&mls_group_id)?;
+    let group_id = mdk_core::prelude::GroupId::from_slice(&group_id_bytes);
+    let account = whitenoise.find_account_by_pubkey(&pubkey).await?;
+
+    whitenoise
+        .set_chat_archived(&account, &group_id, false, false)
+        .await?;
+
+    Ok(())
+}
+
 /// Retrieves the chat list for an account.
 ///
 /// Returns a list of chat summaries sorted by:
 /// 1. Pinned chats first (sorted by pin_order, lower values first)
 /// 2. Unpinned chats sorted by last activity (most recent first)
 /// 3. Groups without messages are sorted by creation date
+///
+/// Only chats matching `filter` are returned.
 #[frb]
-pub async fn get_chat_list(account_pubkey: String) -> Result<Vec<ChatSummary>, ApiError> {
+pub async fn get_chat_list(
+    account_pubkey: String,
+    filter: ChatListFilter,
+) -> Result<Vec<ChatSummary>, ApiError> {
     let whitenoise = Whitenoise::get_instance()?;
     let pubkey = PublicKey::parse(&account_pubkey)?;
     let account = whitenoise.find_account_by_pubkey(&pubkey).await?;
     let chat_list = whitenoise.get_chat_list(&account).await?;
-    Ok(chat_list.into_iter().map(|item| item.into()).collect())
+    Ok(chat_list
+        .into_iter()
+        .map(ChatSummary::from)
+        .filter(|item| filter.matches(item))
+        .collect())
 }
 
 /// Subscribe to real-time chat list updates for an account.
@@ -169,11 +361,16 @@ pub async fn get_chat_list(account_pubkey: String) -> Result<Vec<ChatSummary>, A
 /// The stream first emits an `InitialSnapshot` containing all current chats,
 /// then emits `Update` items as chats are created, receive new messages, or have messages deleted.
 ///
+/// Only chats matching `filter` are included. `ChatArchived`, `ChatUnarchived`
+/// and `GroupLeft` updates are always emitted so chats can be moved in or out
+/// of the list.
+///
 /// The initial snapshot is race-condition free: any updates that arrive between
 /// subscribing and fetching are merged into the snapshot.
 #[frb]
 pub async fn subscribe_to_chat_list(
     account_pubkey: String,
+    filter: ChatListFilter,
     sink: StreamSink<ChatListStreamItem>,
 ) -> Result<(), ApiError> {
     let whitenoise = Whitenoise::get_instance()?;
@@ -186,7 +383,8 @@ pub async fn subscribe_to_chat_list(
     let initial_items: Vec<ChatSummary> = subscription
         .initial_items
         .into_iter()
-        .map(|item| item.into())
+        .map(ChatSummary::from)
+        .filter(|item| filter.matches(item))
         .collect();
 
     if sink
@@ -203,9 +401,17 @@ pub async fn subscribe_to_chat_list(
     loop {
         match rx.recv().await {
             Ok(update) => {
-                let item = ChatListStreamItem::Update {
-                    update: update.into(),
-                };
+                let update: ChatListUpdate = update.into();
+                let moved = matches!(
+                    update.trigger,
+                    ChatListUpdateTrigger::ChatArchived
+                        | ChatListUpdateTrigger::ChatUnarchived
+                        | ChatListUpdateTrigger::GroupLeft
+                );
+                if !moved && !filter.matches(&update.item) {
+                    continue;
+                }
+                let item = ChatListStreamItem::Update { update };
                 if sink.add(item).is_err() {
                     break; // Sink closed
                 }
@@ -250,4 +456,73 @@ mod tests {
             WhitenoiseChatListUpdateTrigger::LastMessageDeleted.into();
         assert_eq!(trigger, ChatListUpdateTrigger::LastMessageDeleted);
     }
+
+    #[test]
+    fn test_chat_list_update_trigger_conversion_chat_archived() {
+        let trigger: ChatListUpdateTrigger = WhitenoiseChatListUpdateTrigger::ChatArchived.into();
+        assert_eq!(trigger, ChatListUpdateTrigger::ChatArchived);
+    }
+
+    #[test]
+    fn test_chat_list_update_trigger_conversion_chat_unarchived() {
+        let trigger: ChatListUpdateTrigger = WhitenoiseChatListUpdateTrigger::ChatUnarchived.into();
+        assert_eq!(trigger, ChatListUpdateTrigger::ChatUnarchived);
+    }
+
+    #[test]
+    fn test_chat_list_update_trigger_conversion_group_left() {
+        let trigger: ChatListUpdateTrigger = WhitenoiseChatListUpdateTrigger::GroupLeft.into();
+        assert_eq!(trigger, ChatListUpdateTrigger::GroupLeft);
+    }
+
+    #[test]
+    fn test_chat_list_update_trigger_conversion_group_metadata_changed() {
+        let trigger: ChatListUpdateTrigger =
+            WhitenoiseChatListUpdateTrigger::GroupMetadataChanged.into();
+        assert_eq!(trigger, ChatListUpdateTrigger::GroupMetadataChanged);
+    }
+
+    #[test]
+    fn test_chat_list_update_trigger_conversion_group_members_changed() {
+        let trigger: ChatListUpdateTrigger =
+            WhitenoiseChatListUpdateTrigger::GroupMembersChanged.into();
+        assert_eq!(trigger, ChatListUpdateTrigger::GroupMembersChanged);
+    }
+
+    #[test]
+    fn test_chat_list_update_trigger_conversion_group_admins_changed() {
+        let trigger: ChatListUpdateTrigger =
+            WhitenoiseChatListUpdateTrigger::GroupAdminsChanged.into();
+        assert_eq!(trigger, ChatListUpdateTrigger::GroupAdminsChanged);
+    }
+
+    #[test]
+    fn test_notification_level_conversion_round_trip() {
+        for level in [
+            NotificationLevel::All,
+            NotificationLevel::MentionsOnly,
+            NotificationLevel::Muted,
+        ] {
+            let whitenoise_level: WhitenoiseNotificationLevel = level.clone().into();
+            let converted: NotificationLevel = whitenoise_level.into();
+            assert_eq!(converted, level);
+        }
+    }
+
+    fn summary(archived: bool) -> ChatSummary {
+        ChatSummary {
+            archived,
+            ..ChatSummary::for_test("00")
+        }
+    }
+
+    #[test]
+    fn test_chat_list_filter_matches() {
+        assert!(ChatListFilter::Active.matches(&summary(false)));
+        assert!(!ChatListFilter::Active.matches(&summary(true)));
+        assert!(ChatListFilter::Archived.matches(&summary(true)));
+        assert!(!ChatListFilter::Archived.matches(&summary(false)));
+        assert!(ChatListFilter::All.matches(&summary(true)));
+        assert!(ChatListFilter::All.matches(&summary(false)));
+    }
 }
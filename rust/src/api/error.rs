@@ -28,6 +28,30 @@ pub enum ApiError {
     #[error("Nostr hex error: {message}")]
     NostrHex { message: String },
 
+    /// The account must be a group admin to perform this operation
+    #[error("Not a group admin: {message}")]
+    NotGroupAdmin { message: String },
+
+    /// The operation would leave the group without any admin
+    #[error("Last group admin: {message}")]
+    LastGroupAdmin { message: String },
+
+    /// The target public key is not a member of the group
+    #[error("Not a group member: {message}")]
+    NotGroupMember { message: String },
+
+    /// The password does not decrypt the encrypted secret key
+    #[error("Wrong password: {message}")]
+    WrongPassword { message: String },
+
+    /// A password is needed but none, or an empty one, was given
+    #[error("Password required: {message}")]
+    PasswordRequired { message: String },
+
+    /// The encrypted secret key (ncryptsec) is malformed or uses unsupported parameters
+    #[error("Invalid encrypted key: {message}")]
+    InvalidEncryptedKey { message: String },
+
     #[error("Other error: {message}")]
     Other { message: String },
 }
@@ -89,6 +113,20 @@ impl From<hex::FromHexError> for ApiError {
     }
 }
 
+impl From<nostr_sdk::nips::nip49::Error> for ApiError {
+    fn from(error: nostr_sdk::nips::nip49::Error) -> Self {
+        match error {
+            // Decryption only fails authentication when the password is wrong
+            nostr_sdk::nips::nip49::Error::ChaCha20Poly1305(_) => Self::WrongPassword {
+                message: error.to_string(),
+            },
+            _ => Self::InvalidEncryptedKey {
+                message: error.to_string(),
+            },
+        }
+    }
+}
+
 impl From<anyhow::Error> for ApiError {
     fn from(error: anyhow::Error) -> Self {
         Self::Other {
@@ -108,6 +146,12 @@ impl ApiError {
             ApiError::NostrEvent { .. } => "NostrEvent".to_string(),
             ApiError::NostrParse { .. } => "NostrParse".to_string(),
             ApiError::NostrHex { .. } => "NostrHex".to_string(),
+            ApiError::NotGroupAdmin { .. } => "NotGroupAdmin".to_string(),
+            ApiError::LastGroupAdmin { .. } => "LastGroupAdmin".to_string(),
+            ApiError::NotGroupMember { .. } => "NotGroupMember".to_string(),
+            ApiError::WrongPassword { .. } => "WrongPassword".to_string(),
+            ApiError::PasswordRequired { .. } => "PasswordRequired".to_string(),
+            ApiError::InvalidEncryptedKey { .. } => "InvalidEncryptedKey".to_string(),
             ApiError::Other { .. } => "Other".to_string(),
         }
     }
@@ -122,6 +166,12 @@ impl ApiError {
             ApiError::NostrEvent { message } => message.clone(),
             ApiError::NostrParse { message } => message.clone(),
             ApiError::NostrHex { message } => message.clone(),
+            ApiError::NotGroupAdmin { message } => message.clone(),
+            ApiError::LastGroupAdmin { message } => message.clone(),
+            ApiError::NotGroupMember { message } => message.clone(),
+            ApiError::WrongPassword { message } => message.clone(),
+            ApiError::PasswordRequired { message } => message.clone(),
+            ApiError::InvalidEncryptedKey { message } => message.clone(),
             ApiError::Other { message } => message.clone(),
         }
     }
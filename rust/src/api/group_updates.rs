@@ -0,0 +1,212 @@
+use crate::api::error::ApiError;
+use crate::api::groups::Group;
+use crate::api::utils::group_id_from_string;
+use crate::frb_generated::StreamSink;
+use flutter_rust_bridge::frb;
+use nostr_sdk::PublicKey;
+use whitenoise::{
+    GroupUpdate as WhitenoiseGroupUpdate, GroupUpdateTrigger as WhitenoiseGroupUpdateTrigger,
+    Whitenoise,
+};
+
+/// What changed in a group.
+///
+/// Public keys are hex strings.
+#[frb]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GroupUpdateTrigger {
+    /// Members were added to the group by `added_by`
+    MembersAdded {
+        members: Vec<String>,
+        added_by: String,
+    },
+    /// Members were removed from the group by `removed_by`, or left it
+    /// themselves when `removed_by` is one of `members`
+    MembersRemoved {
+        members: Vec<String>,
+        removed_by: String,
+    },
+    /// The admin list changed
+    AdminsChanged {
+        added: Vec<String>,
+        removed: Vec<String>,
+    },
+    /// The group name changed
+    NameChanged,
+    /// The group description changed
+    DescriptionChanged,
+    /// The group image changed
+    ImageChanged,
+    /// A commit advanced the group to a new epoch
+    EpochAdvanced { epoch: u64 },
+}
+
+impl From<WhitenoiseGroupUpdateTrigger> for GroupUpdateTrigger {
+    fn from(trigger: WhitenoiseGroupUpdateTrigger) -> Self {
+        let to_hex = |pubkeys: Vec<PublicKey>| pubkeys.iter().map(|pk| pk.to_hex()).collect();
+        match trigger {
+            WhitenoiseGroupUpdateTrigger::MembersAdded { members, added_by } => {
+                Self::MembersAdded {
+                    members: to_hex(members),
+                    added_by: added_by.to_hex(),
+                }
+            }
+            WhitenoiseGroupUpdateTrigger::MembersRemoved {
+                members,
+                removed_by,
+            } => Self::MembersRemoved {
+                members: to_hex(members),
+                removed_by: removed_by.to_hex(),
+            },
+            WhitenoiseGroupUpdateTrigger::AdminsChanged { added, removed } => Self::AdminsChanged {
+                added: to_hex(added),
+                removed: to_hex(removed),
+            },
+            WhitenoiseGroupUpdateTrigger::NameChanged => Self::NameChanged,
+            WhitenoiseGroupUpdateTrigger::DescriptionChanged => Self::DescriptionChanged,
+            WhitenoiseGroupUpdateTrigger::ImageChanged => Self::ImageChanged,
+            WhitenoiseGroupUpdateTrigger::EpochAdvanced { epoch } => Self::EpochAdvanced { epoch },
+        }
+    }
+}
+
+/// A real-time update for a group.
+///
+/// Contains the trigger indicating what changed and the complete,
+/// current state of the group.
+#[frb(non_opaque)]
+#[derive(Debug, Clone)]
+pub struct GroupUpdate {
+    pub trigger: GroupUpdateTrigger,
+    pub group: Group,
+}
+
+impl From<WhitenoiseGroupUpdate> for GroupUpdate {
+    fn from(update: WhitenoiseGroupUpdate) -> Self {
+        Self {
+            trigger: update.trigger.into(),
+            group: update.group.into(),
+        }
+    }
+}
+
+/// Stream item emitted by `subscribe_to_group`.
+///
+/// The first item is always `InitialSnapshot` containing the current group.
+/// Subsequent items are `Update` containing real-time changes.
+#[frb]
+#[derive(Debug, Clone)]
+pub enum GroupStreamItem {
+    /// State of the group at subscription time
+    InitialSnapshot { group: Group },
+    /// Real-time update of the group
+    Update { update: GroupUpdate },
+}
+
+/// Subscribe to membership and metadata changes of a group.
+///
+/// The stream first emits an `InitialSnapshot` with the current group, then
+/// emits `Update` items when members are added or removed, admins change, the
+/// name, description or image change, or the group advances to a new epoch.
+#[frb]
+pub async fn subscribe_to_group(
+    account_pubkey: String,
+    group_id: String,
+    sink: StreamSink<GroupStreamItem>,
+) -> Result<(), ApiError> {
+    let whitenoise = Whitenoise::get_instance()?;
+    let pubkey = PublicKey::parse(&account_pubkey)?;
+    let account = whitenoise.find_account_by_pubkey(&pubkey).await?;
+    let group_id = group_id_from_string(&group_id)?;
+
+    let subscription = whitenoise.subscribe_to_group(&account, &group_id).await?;
+
+    // Emit initial snapshot first
+    if sink
+        .add(GroupStreamItem::InitialSnapshot {
+            group: subscription.initial_group.into(),
+        })
+        .is_err()
+    {
+        return Ok(()); // Sink closed, exit gracefully
+    }
+
+    // Stream real-time updates
+    let mut rx = subscription.updates;
+    loop {
+        match rx.recv().await {
+            Ok(update) => {
+                let item = GroupStreamItem::Update {
+                    update: update.into(),
+                };
+                if sink.add(item).is_err() {
+                    break; // Sink closed
+                }
+            }
+            Err(tokio::sync::broadcast::error::RecvError::Lagged(_)) => {
+                // Slow consumer missed some updates - safe to continue since
+                // each update contains the complete group state
+                continue;
+            }
+            Err(tokio::sync::broadcast::error::RecvError::Closed) => {
+                break; // Channel closed
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nostr_sdk::Keys;
+
+    #[test]
+    fn test_group_update_trigger_conversion_members_added() {
+        let member = Keys::generate().public_key();
+        let admin = Keys::generate().public_key();
+        let trigger: GroupUpdateTrigger = WhitenoiseGroupUpdateTrigger::MembersAdded {
+            members: vec![member],
+            added_by: admin,
+        }
+        .into();
+        assert_eq!(
+            trigger,
+            GroupUpdateTrigger::MembersAdded {
+                members: vec![member.to_hex()],
+                added_by: admin.to_hex(),
+            }
+        );
+    }
+
+    #[test]
+    fn test_group_update_trigger_conversion_admins_changed() {
+        let admin = Keys::generate().public_key();
+        let trigger: GroupUpdateTrigger = WhitenoiseGroupUpdateTrigger::AdminsChanged {
+            added: vec![admin],
+            removed: vec![],
+        }
+        .into();
+        assert_eq!(
+            trigger,
+            GroupUpdateTrigger::AdminsChanged {
+                added: vec![admin.to_hex()],
+                removed: vec![],
+            }
+        );
+    }
+
+    #[test]
+    fn test_group_update_trigger_conversion_name_changed() {
+        let trigger: GroupUpdateTrigger = WhitenoiseGroupUpdateTrigger::NameChanged.into();
+        assert_eq!(trigger, GroupUpdateTrigger::NameChanged);
+    }
+
+    #[test]
+    fn test_group_update_trigger_conversion_epoch_advanced() {
+        let trigger: GroupUpdateTrigger =
+            WhitenoiseGroupUpdateTrigger::EpochAdvanced { epoch: 7 }.into();
+        assert_eq!(trigger, GroupUpdateTrigger::EpochAdvanced { epoch: 7 });
+    }
+}
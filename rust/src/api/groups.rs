@@ -1,13 +1,18 @@
-use crate::api::{error::ApiError, group_id_from_string, group_id_to_string};
+use crate::api::{
+    chat_list::ChatSummary, error::ApiError, get_default_blossom_server_url, group_id_from_string,
+    group_id_to_string, messages::SystemEvent, users::User, utils::timestamp_to_datetime,
+};
 use chrono::{DateTime, Utc};
 use flutter_rust_bridge::frb;
 use mdk_core::prelude::group_types::Group as WhitenoiseGroup;
 use mdk_core::prelude::group_types::GroupState as WhitenoiseGroupState;
-use mdk_core::prelude::{NostrGroupConfigData, NostrGroupDataUpdate};
+use mdk_core::prelude::{GroupId, NostrGroupConfigData, NostrGroupDataUpdate};
 use nostr_sdk::prelude::*;
+use std::collections::HashMap;
 use whitenoise::{
+    Account as WhitenoiseAccount, AccountGroup as WhitenoiseAccountGroup,
     GroupInformation as WhitenoiseGroupInformation, GroupType as WhitenoiseGroupType, RelayType,
-    Whitenoise,
+    UserSyncMode, Whitenoise,
 };
 
 #[frb(non_opaque)]
@@ -60,9 +65,35 @@ pub struct FlutterGroupDataUpdate {
     pub image_nonce: Option<[u8; 12]>,
 }
 
-impl From<FlutterGroupDataUpdate> for NostrGroupDataUpdate {
-    fn from(group_data: FlutterGroupDataUpdate) -> Self {
-        Self {
+impl TryFrom<FlutterGroupDataUpdate> for NostrGroupDataUpdate {
+    type Error = ApiError;
+
+    fn try_from(group_data: FlutterGroupDataUpdate) -> Result<Self, Self::Error> {
+        let relays = group_data
+            .relays
+            .map(|relays| {
+                relays
+                    .into_iter()
+                    .map(|r| RelayUrl::parse(&r))
+                    .collect::<Result<Vec<_>, _>>()
+            })
+            .transpose()?;
+        let admins = group_data
+            .admins
+            .map(|admins| {
+                admins
+                    .into_iter()
+                    .map(|a| PublicKey::parse(&a))
+                    .collect::<Result<Vec<_>, _>>()
+            })
+            .transpose()?;
+        if admins.as_ref().is_some_and(|admins| admins.is_empty()) {
+            return Err(ApiError::LastGroupAdmin {
+                message: "A group must keep at least one admin".to_string(),
+            });
+        }
+
+        Ok(Self {
             name: group_data.name,
             description: group_data.description,
             image_key: group_data.image_key.map(Some),
@@ -70,22 +101,50 @@ impl From<FlutterGroupDataUpdate> for NostrGroupDataUpdate {
             image_nonce: group_data.image_nonce.map(Some),
             image_upload_key: None,
             nostr_group_id: None,
-            relays: group_data.relays.map(|relays| {
-                relays
-                    .into_iter()
-                    .filter_map(|r| RelayUrl::parse(&r).ok())
-                    .collect()
-            }),
-            admins: group_data.admins.map(|admins| {
-                admins
-                    .into_iter()
-                    .filter_map(|a| PublicKey::parse(&a).ok())
-                    .collect()
-            }),
-        }
+            relays: relays.map(|relays| relays.into_iter().collect()),
+            admins: admins.map(|admins| admins.into_iter().collect()),
+        })
     }
 }
 
+/// Builds a group data update that only replaces the admin list.
+fn admins_update(admins: Vec<PublicKey>) -> NostrGroupDataUpdate {
+    NostrGroupDataUpdate {
+        name: None,
+        description: None,
+        image_key: None,
+        image_hash: None,
+        image_nonce: None,
+        image_upload_key: None,
+        nostr_group_id: None,
+        relays: None,
+        admins: Some(admins.into_iter().collect()),
+    }
+}
+
+/// Ensures `pubkey` is one of the group `admins`.
+pub(crate) fn ensure_group_admin(admins: &[PublicKey], pubkey: &PublicKey) -> Result<(), ApiError> {
+    if admins.contains(pubkey) {
+        Ok(())
+    } else {
+        Err(ApiError::NotGroupAdmin {
+            message: format!("{} is not an admin of this group", pubkey.to_hex()),
+        })
+    }
+}
+
+/// Returns the admin list with `admin` removed, refusing to remove the last admin.
+fn admins_without(admins: &[PublicKey], admin: &PublicKey) -> Result<Vec<PublicKey>, ApiError> {
+    ensure_group_admin(admins, admin)?;
+    let remaining: Vec<PublicKey> = admins.iter().filter(|a| *a != admin).copied().collect();
+    if remaining.is_empty() {
+        return Err(ApiError::LastGroupAdmin {
+            message: "A group must keep at least one admin".to_string(),
+        });
+    }
+    Ok(remaining)
+}
+
 impl Group {
     #[frb]
     pub async fn group_type(&self, account_pubkey: String) -> Result<GroupType, ApiError> {
@@ -131,7 +190,7 @@ impl Group {
         let parsed_pubkey = PublicKey::parse(&account_pubkey)?;
         let account = whitenoise.find_account_by_pubkey(&parsed_pubkey).await?;
         whitenoise
-            .update_group_data(&account, &mls_group_id, group_data.into())
+            .update_group_data(&account, &mls_group_id, group_data.try_into()?)
             .await
             .map_err(ApiError::from)
     }
@@ -139,7 +198,7 @@ impl Group {
 
 // Define our own GroupState enum that can be used by Dart
 #[frb]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum GroupState {
     Active,
     Inactive,
@@ -197,13 +256,132 @@ impl From<WhitenoiseGroupInformation> for GroupInformation {
     }
 }
 
+/// Which groups `groups` returns, by MLS group state.
+#[frb]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GroupStateFilter {
+    /// Groups the account is a member of
+    Active,
+    /// Groups the account left or was removed from
+    Inactive,
+    /// Groups the account has not joined yet
+    Pending,
+    /// Groups in any state
+    All,
+}
+
+impl GroupStateFilter {
+    fn matches(&self, state: &GroupState) -> bool {
+        match self {
+            Self::Active => *state == GroupState::Active,
+            Self::Inactive => *state == GroupState::Inactive,
+            Self::Pending => *state == GroupState::Pending,
+            Self::All => true,
+        }
+    }
+}
+
+/// A group together with its information (including its type), so lists can
+/// be rendered without a lookup per group.
+#[frb(non_opaque)]
+#[derive(Debug, Clone)]
+pub struct GroupWithInfo {
+    pub group: Group,
+    pub information: GroupInformation,
+}
+
+/// Loads the account's groups and their information in a single query.
+///
+/// Groups without stored information, e.g. while a welcome is still being
+/// processed, are left out rather than failing the whole list.
+async fn groups_with_info(
+    whitenoise: &Whitenoise,
+    pubkey: PublicKey,
+    filter: GroupStateFilter,
+) -> Result<Vec<GroupWithInfo>, ApiError> {
+    let account = whitenoise.find_account_by_pubkey(&pubkey).await?;
+    let groups: Vec<Group> = whitenoise
+        .groups(&account, filter == GroupStateFilter::Active)
+        .await?
+        .into_iter()
+        .map(Group::from)
+        .filter(|group| filter.matches(&group.state))
+        .collect();
+    let group_ids = groups
+        .iter()
+        .map(|g| group_id_from_string(&g.mls_group_id))
+        .collect::<Result<Vec<_>, _>>()?;
+    let mut informations: HashMap<String, GroupInformation> = whitenoise
+        .get_group_information_by_mls_group_ids(pubkey, &group_ids)
+        .await?
+        .into_iter()
+        .map(|info| {
+            let info: GroupInformation = info.into();
+            (info.mls_group_id.clone(), info)
+        })
+        .collect();
+
+    Ok(groups
+        .into_iter()
+        .filter_map(|group| {
+            let information = informations.remove(&group.mls_group_id)?;
+            Some(GroupWithInfo { group, information })
+        })
+        .collect())
+}
+
+/// Returns the account's active groups with their information.
 #[frb]
-pub async fn active_groups(pubkey: String) -> Result<Vec<Group>, ApiError> {
+pub async fn active_groups(pubkey: String) -> Result<Vec<GroupWithInfo>, ApiError> {
     let whitenoise = Whitenoise::get_instance()?;
     let pubkey = PublicKey::parse(&pubkey)?;
+    groups_with_info(whitenoise, pubkey, GroupStateFilter::Active).await
+}
+
+/// Returns all the account's groups, including inactive and pending ones,
+/// with their information.
+#[frb]
+pub async fn all_groups(pubkey: String) -> Result<Vec<GroupWithInfo>, ApiError> {
+    let whitenoise = Whitenoise::get_instance()?;
+    let pubkey = PublicKey::parse(&pubkey)?;
+    groups_with_info(whitenoise, pubkey, GroupStateFilter::All).await
+}
+
+/// Returns the account's groups in the states selected by `filter`, with
+/// their information.
+#[frb]
+pub async fn groups(
+    account_pubkey: String,
+    filter: GroupStateFilter,
+) -> Result<Vec<GroupWithInfo>, ApiError> {
+    let whitenoise = Whitenoise::get_instance()?;
+    let pubkey = PublicKey::parse(&account_pubkey)?;
+    groups_with_info(whitenoise, pubkey, filter).await
+}
+
+/// Permanently deletes the local data of an inactive group: its messages,
+/// cached media and MLS state.
+///
+/// Only groups the account left or was removed from can be deleted; leave an
+/// active group first with `leave_group`.
+#[frb]
+pub async fn delete_group_data(account_pubkey: String, group_id: String) -> Result<(), ApiError> {
+    let whitenoise = Whitenoise::get_instance()?;
+    let pubkey = PublicKey::parse(&account_pubkey)?;
     let account = whitenoise.find_account_by_pubkey(&pubkey).await?;
-    let groups = whitenoise.groups(&account, true).await?;
-    Ok(groups.into_iter().map(|g| g.into()).collect())
+    let group_id = group_id_from_string(&group_id)?;
+
+    let group: Group = whitenoise.group(&account, &group_id).await?.into();
+    if group.state != GroupState::Inactive {
+        return Err(ApiError::Other {
+            message: "Only inactive groups can be deleted".to_string(),
+        });
+    }
+
+    whitenoise
+        .delete_group_data(&account, &group_id)
+        .await
+        .map_err(ApiError::from)
 }
 
 #[frb]
@@ -226,7 +404,247 @@ pub async fn group_admins(pubkey: String, group_id: String) -> Result<Vec<String
     Ok(admins.into_iter().map(|a| a.to_hex()).collect())
 }
 
+/// A group member with profile metadata and role.
+#[frb(non_opaque)]
+#[derive(Debug, Clone)]
+pub struct GroupMember {
+    pub user: User,
+    pub is_admin: bool,
+    /// When the member was added (None for founding members or when the
+    /// commit predates the local history)
+    pub joined_at: Option<DateTime<Utc>>,
+    /// Public key (hex) of the member who added them
+    pub added_by: Option<String>,
+    /// Whether the member has a published, unexpired key package, i.e. can be
+    /// re-added to other groups
+    pub has_key_package: bool,
+}
+
+/// Maps each added member (hex) to when and by whom they were last added,
+/// based on the system entries of the group timeline, oldest first.
+fn member_additions(
+    system_events: &[(DateTime<Utc>, SystemEvent)],
+) -> HashMap<String, (DateTime<Utc>, String)> {
+    let mut additions = HashMap::new();
+    for (created_at, event) in system_events {
+        if let SystemEvent::MembersAdded { actor, members } = event {
+            for member in members {
+                additions.insert(member.clone(), (*created_at, actor.clone()));
+            }
+        }
+    }
+    additions
+}
+
+/// Returns every member of a group with their metadata, admin flag, join
+/// information and key package availability in a single call.
+///
+/// User metadata is taken from the local cache and refreshed in the background.
+/// Key packages are looked up concurrently; a member whose key package cannot
+/// be fetched is reported without one.
+#[frb]
+pub async fn group_member_details(
+    account_pubkey: String,
+    group_id: String,
+) -> Result<Vec<GroupMember>, ApiError> {
+    let whitenoise = Whitenoise::get_instance()?;
+    let pubkey = PublicKey::parse(&account_pubkey)?;
+    let account = whitenoise.find_account_by_pubkey(&pubkey).await?;
+    let group_id = group_id_from_string(&group_id)?;
+
+    let members = whitenoise.group_members(&account, &group_id).await?;
+    let admins = whitenoise.group_admins(&account, &group_id).await?;
+    // Only the system entries are needed, skip converting the other messages
+    let system_events: Vec<(DateTime<Utc>, SystemEvent)> = whitenoise
+        .fetch_aggregated_messages_for_group(&pubkey, &group_id)
+        .await?
+        .iter()
+        .filter_map(|m| {
+            let event = m.system_event.as_ref()?;
+            Some((timestamp_to_datetime(m.created_at), event.into()))
+        })
+        .collect();
+    let additions = member_additions(&system_events);
+
+    let mut tasks = tokio::task::JoinSet::new();
+    for (index, member) in members.iter().copied().enumerate() {
+        tasks.spawn(async move {
+            let whitenoise = Whitenoise::get_instance()?;
+            let user = whitenoise
+                .find_or_create_user_by_pubkey(&member, UserSyncMode::Background)
+                .await?;
+            // An unreachable key package relay only means the key package is unknown
+            let has_key_package = matches!(
+                user.key_package_event(whitenoise).await,
+                Ok(Some(event)) if !event.is_expired()
+            );
+            Ok::<_, ApiError>((index, User::from(user), has_key_package))
+        });
+    }
+
+    let mut users: Vec<Option<(User, bool)>> = members.iter().map(|_| None).collect();
+    while let Some(result) = tasks.join_next().await {
+        let (index, user, has_key_package) = result.map_err(|e| ApiError::Other {
+            message: e.to_string(),
+        })??;
+        users[index] = Some((user, has_key_package));
+    }
+
+    Ok(members
+        .into_iter()
+        .zip(users)
+        .filter_map(|(member, user)| {
+            let (user, has_key_package) = user?;
+            let addition = additions.get(&member.to_hex());
+            Some(GroupMember {
+                user,
+                is_admin: admins.contains(&member),
+                joined_at: addition.map(|(joined_at, _)| *joined_at),
+                added_by: addition.map(|(_, added_by)| added_by.clone()),
+                has_key_package,
+            })
+        })
+        .collect())
+}
+
+/// Whether a user can be invited to a group.
+#[frb]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InviteStatus {
+    /// The user has a valid key package and can be invited
+    Ready,
+    /// The public key could not be parsed
+    InvalidPubkey,
+    /// The user has not published a key package
+    NoKeyPackage,
+    /// The user's latest key package has expired
+    ExpiredKeyPackage,
+    /// The user's key package relays are unknown or could not be reached
+    UnreachableRelays,
+}
+
+/// Invite status of a single user, as returned by `check_invitable`.
+#[frb(non_opaque)]
+#[derive(Debug, Clone)]
+pub struct InviteCheck {
+    pub pubkey: String,
+    pub status: InviteStatus,
+}
+
+/// A member left out of a group commit.
+#[frb(non_opaque)]
+#[derive(Debug, Clone)]
+pub struct SkippedMember {
+    pub pubkey: String,
+    /// Why the member could not be invited (never `Ready`)
+    pub status: InviteStatus,
+}
+
+/// Result of `create_group`: the new group and the members that could not be added.
+#[frb(non_opaque)]
+#[derive(Debug, Clone)]
+pub struct CreateGroupResult {
+    pub group: Group,
+    pub skipped_members: Vec<SkippedMember>,
+    /// Why the group image could not be set. The group exists either way;
+    /// retry with `upload_group_image`.
+    pub image_error: Option<String>,
+}
+
+/// Checks that a user has a valid key package on at least one reachable relay.
+///
+/// Failing to resolve the user or their relays is reported as
+/// `UnreachableRelays` so one user never fails a whole batch of checks.
+async fn invite_status(whitenoise: &Whitenoise, pubkey: &str) -> InviteStatus {
+    let Ok(pubkey) = PublicKey::parse(pubkey) else {
+        return InviteStatus::InvalidPubkey;
+    };
+    let Ok(user) = whitenoise
+        .find_or_create_user_by_pubkey(&pubkey, UserSyncMode::Blocking)
+        .await
+    else {
+        return InviteStatus::UnreachableRelays;
+    };
+    let relays = user.relays_by_type(RelayType::KeyPackage, whitenoise).await;
+    if !matches!(relays, Ok(relays) if !relays.is_empty()) {
+        return InviteStatus::UnreachableRelays;
+    }
+    match user.key_package_event(whitenoise).await {
+        Ok(Some(event)) if event.is_expired() => InviteStatus::ExpiredKeyPackage,
+        Ok(Some(_)) => InviteStatus::Ready,
+        Ok(None) => InviteStatus::NoKeyPackage,
+        Err(_) => InviteStatus::UnreachableRelays,
+    }
+}
+
+/// Resolves the invite status of every user concurrently, keeping the input order.
+async fn invite_checks(pubkeys: Vec<String>) -> Result<Vec<InviteCheck>, ApiError> {
+    let mut tasks = tokio::task::JoinSet::new();
+    for (index, pubkey) in pubkeys.iter().cloned().enumerate() {
+        tasks.spawn(async move {
+            let whitenoise = Whitenoise::get_instance()?;
+            let status = invite_status(whitenoise, &pubkey).await;
+            Ok::<_, ApiError>((index, status))
+        });
+    }
+
+    let mut statuses = vec![InviteStatus::UnreachableRelays; pubkeys.len()];
+    while let Some(result) = tasks.join_next().await {
+        let (index, status) = result.map_err(|e| ApiError::Other {
+            message: e.to_string(),
+        })??;
+        statuses[index] = status;
+    }
+
+    Ok(pubkeys
+        .into_iter()
+        .zip(statuses)
+        .map(|(pubkey, status)| InviteCheck { pubkey, status })
+        .collect())
+}
+
+/// Splits invite checks into the members that can be invited and a report of the others.
+fn partition_invitable(
+    checks: Vec<InviteCheck>,
+) -> Result<(Vec<PublicKey>, Vec<SkippedMember>), ApiError> {
+    let mut ready = Vec::new();
+    let mut skipped = Vec::new();
+    for check in checks {
+        if check.status == InviteStatus::Ready {
+            ready.push(PublicKey::parse(&check.pubkey)?);
+        } else {
+            skipped.push(SkippedMember {
+                pubkey: check.pubkey,
+                status: check.status,
+            });
+        }
+    }
+    Ok((ready, skipped))
+}
+
+/// Checks whether users can be invited to a group.
+///
+/// Every user's key package relays are resolved concurrently. Statuses are
+/// returned in the order of `pubkeys`.
 #[frb]
+pub async fn check_invitable(pubkeys: Vec<String>) -> Result<Vec<InviteCheck>, ApiError> {
+    invite_checks(pubkeys).await
+}
+
+/// Creates a group and invites the members that can be reached.
+///
+/// The group relays default to the creator's NIP-65 relays; pass `relays` to
+/// pin the group to a specific relay set instead. When `image_path` is set the
+/// file is uploaded to the default Blossom server and committed as the group
+/// avatar; as the group already exists at that point, a failure is reported in
+/// `image_error` instead of failing the call.
+///
+/// Every member is checked for a key package on a reachable relay before the
+/// commit. Members failing the check are left out, dropped from the admins and
+/// reported in `skipped_members`; a direct message fails if its peer cannot be
+/// invited.
+#[frb]
+#[allow(clippy::too_many_arguments)]
 pub async fn create_group(
     creator_pubkey: String,
     member_pubkeys: Vec<String>,
@@ -234,7 +652,9 @@ pub async fn create_group(
     group_name: String,
     group_description: String,
     group_type: GroupType,
-) -> Result<Group, ApiError> {
+    relays: Option<Vec<String>>,
+    image_path: Option<String>,
+) -> Result<CreateGroupResult, ApiError> {
     let whitenoise_group_type = match group_type {
         GroupType::DirectMessage => WhitenoiseGroupType::DirectMessage,
         GroupType::Group => WhitenoiseGroupType::Group,
@@ -244,28 +664,55 @@ pub async fn create_group(
     let creator_pubkey = PublicKey::parse(&creator_pubkey)?;
     let creator_account = whitenoise.find_account_by_pubkey(&creator_pubkey).await?;
 
-    // Fetch the creator's Nostr relays to include in the group configuration
-    let nostr_relays = creator_account.relays(RelayType::Nip65, whitenoise).await?;
-    let admin_pubkeys = admin_pubkeys
+    let relays = match relays {
+        Some(relays) => {
+            if relays.is_empty() {
+                return Err(ApiError::Other {
+                    message: "A group needs at least one relay".to_string(),
+                });
+            }
+            relays
+                .iter()
+                .map(|r| RelayUrl::parse(r))
+                .collect::<Result<Vec<_>, _>>()?
+        }
+        // Fetch the creator's Nostr relays to include in the group configuration
+        None => creator_account
+            .relays(RelayType::Nip65, whitenoise)
+            .await?
+            .into_iter()
+            .map(|r| r.url)
+            .collect(),
+    };
+    let mut admin_pubkeys = admin_pubkeys
         .into_iter()
         .map(|pk| PublicKey::parse(&pk))
         .collect::<Result<Vec<_>, _>>()?;
 
+    let (member_pubkeys, skipped_members) =
+        partition_invitable(invite_checks(member_pubkeys).await?)?;
+    if group_type == GroupType::DirectMessage && member_pubkeys.is_empty() {
+        return Err(ApiError::Other {
+            message: "The direct message peer cannot be invited".to_string(),
+        });
+    }
+    // Skipped members are never added, so they cannot be admins either
+    admin_pubkeys.retain(|admin| {
+        !skipped_members
+            .iter()
+            .any(|skipped| PublicKey::parse(&skipped.pubkey).is_ok_and(|pk| pk == *admin))
+    });
+
     let nostr_group_config = NostrGroupConfigData {
         name: group_name,
         description: group_description,
         image_key: None,
         image_hash: None,
         image_nonce: None,
-        relays: nostr_relays.into_iter().map(|r| r.url).collect(),
+        relays,
         admins: admin_pubkeys,
     };
 
-    let member_pubkeys = member_pubkeys
-        .into_iter()
-        .map(|pk| PublicKey::parse(&pk))
-        .collect::<Result<Vec<_>, _>>()?;
-
     let group = whitenoise
         .create_group(
             &creator_account,
@@ -274,27 +721,140 @@ pub async fn create_group(
             Some(whitenoise_group_type),
         )
         .await?;
-    Ok(group.into())
+
+    let mut image_error = None;
+    let group = match image_path {
+        Some(image_path) => {
+            let image_result = match Url::parse(&get_default_blossom_server_url()) {
+                Ok(server) => set_group_image(
+                    whitenoise,
+                    &creator_account,
+                    &group.mls_group_id,
+                    &image_path,
+                    server,
+                )
+                .await
+                .map(|_| ()),
+                Err(e) => Err(e.into()),
+            };
+            match image_result {
+                Ok(()) => whitenoise
+                    .group(&creator_account, &group.mls_group_id)
+                    .await
+                    .unwrap_or(group),
+                Err(e) => {
+                    image_error = Some(e.to_string());
+                    group
+                }
+            }
+        }
+        None => group,
+    };
+
+    Ok(CreateGroupResult {
+        group: group.into(),
+        skipped_members,
+        image_error,
+    })
+}
+
+/// Picks the DM both participants converge on when several exist with the same peer.
+///
+/// When both sides create a DM at the same time each ends up with two groups;
+/// the smallest group id wins. Creation times are local to each device, so
+/// only the group id is guaranteed to give both sides the same answer.
+fn canonical_direct_message(candidates: &[ChatSummary]) -> Option<&ChatSummary> {
+    candidates
+        .iter()
+        .min_by(|a, b| a.mls_group_id.cmp(&b.mls_group_id))
+}
+
+/// Returns the DM group with `peer_pubkey`, creating it only if none exists.
+///
+/// Existing DMs are looked up in the account's chat list. If the peer already
+/// invited the account and the invite is still pending, it is accepted and
+/// reused instead of creating a second group. New DMs are created through
+/// `create_group`, so they fail if the peer cannot be invited.
+#[frb]
+pub async fn find_or_create_direct_message(
+    account_pubkey: String,
+    peer_pubkey: String,
+) -> Result<Group, ApiError> {
+    let whitenoise = Whitenoise::get_instance()?;
+    let pubkey = PublicKey::parse(&account_pubkey)?;
+    let account = whitenoise.find_account_by_pubkey(&pubkey).await?;
+    let peer_pubkey = PublicKey::parse(&peer_pubkey)?;
+    let peer_hex = peer_pubkey.to_hex();
+
+    let direct_messages: Vec<ChatSummary> = whitenoise
+        .get_chat_list(&account)
+        .await?
+        .into_iter()
+        .map(ChatSummary::from)
+        .filter(|chat| {
+            chat.group_type == GroupType::DirectMessage
+                && chat.dm_peer_pubkey.as_deref() == Some(peer_hex.as_str())
+        })
+        .collect();
+
+    if let Some(existing) = canonical_direct_message(&direct_messages) {
+        let group_id = group_id_from_string(&existing.mls_group_id)?;
+        if existing.pending_confirmation
+            && let Some(account_group) =
+                WhitenoiseAccountGroup::get(whitenoise, &pubkey, &group_id).await?
+        {
+            account_group.accept(whitenoise).await?;
+        }
+        let group = whitenoise.group(&account, &group_id).await?;
+        return Ok(group.into());
+    }
+
+    let created = create_group(
+        pubkey.to_hex(),
+        vec![peer_hex.clone()],
+        vec![pubkey.to_hex(), peer_hex],
+        String::new(),
+        String::new(),
+        GroupType::DirectMessage,
+        None,
+        None,
+    )
+    .await?;
+    Ok(created.group)
 }
 
+/// Adds members to a group.
+///
+/// With `partial` unset the call fails as a whole if any member cannot be
+/// added. With `partial` set, members are checked first (see
+/// `check_invitable`), only the reachable subset is invited and the others
+/// are returned. No commit is made when nobody can be invited.
 #[frb]
 pub async fn add_members_to_group(
     pubkey: String,
     group_id: String,
     member_pubkeys: Vec<String>,
-) -> Result<(), ApiError> {
+    partial: bool,
+) -> Result<Vec<SkippedMember>, ApiError> {
     let whitenoise = Whitenoise::get_instance()?;
     let pubkey = PublicKey::parse(&pubkey)?;
     let group_id = group_id_from_string(&group_id)?;
     let account = whitenoise.find_account_by_pubkey(&pubkey).await?;
-    let member_pubkeys = member_pubkeys
-        .into_iter()
-        .map(|pk| PublicKey::parse(&pk))
-        .collect::<Result<Vec<_>, _>>()?;
-    whitenoise
-        .add_members_to_group(&account, &group_id, member_pubkeys)
-        .await
-        .map_err(ApiError::from)
+    let (member_pubkeys, skipped_members) = if partial {
+        partition_invitable(invite_checks(member_pubkeys).await?)?
+    } else {
+        let member_pubkeys = member_pubkeys
+            .into_iter()
+            .map(|pk| PublicKey::parse(&pk))
+            .collect::<Result<Vec<_>, _>>()?;
+        (member_pubkeys, vec![])
+    };
+    if !member_pubkeys.is_empty() {
+        whitenoise
+            .add_members_to_group(&account, &group_id, member_pubkeys)
+            .await?;
+    }
+    Ok(skipped_members)
 }
 
 #[frb]
@@ -317,6 +877,96 @@ pub async fn remove_members_from_group(
         .map_err(ApiError::from)
 }
 
+/// Leaves a group as the current member.
+///
+/// Sends an MLS self-remove proposal to the group, marks the local group as
+/// `GroupState::Inactive`, removes the account's `AccountGroup` row and emits a
+/// `ChatListUpdateTrigger::GroupLeft` chat list update.
+///
+/// The last admin of a group cannot leave it while other members remain;
+/// another member must be promoted first. A sole member can always leave.
+#[frb]
+pub async fn leave_group(account_pubkey: String, group_id: String) -> Result<(), ApiError> {
+    let whitenoise = Whitenoise::get_instance()?;
+    let pubkey = PublicKey::parse(&account_pubkey)?;
+    let group_id = group_id_from_string(&group_id)?;
+    let account = whitenoise.find_account_by_pubkey(&pubkey).await?;
+
+    let admins = whitenoise.group_admins(&account, &group_id).await?;
+    if admins.contains(&pubkey) {
+        let members = whitenoise.group_members(&account, &group_id).await?;
+        if members.iter().any(|member| *member != pubkey) {
+            admins_without(&admins, &pubkey)?;
+        }
+    }
+
+    whitenoise
+        .leave_group(&account, &group_id)
+        .await
+        .map_err(ApiError::from)
+}
+
+/// Promotes a group member to admin.
+///
+/// The calling account must be an admin of the group and `member_pubkey` must
+/// be a member of it.
+#[frb]
+pub async fn promote_to_admin(
+    account_pubkey: String,
+    group_id: String,
+    member_pubkey: String,
+) -> Result<(), ApiError> {
+    let whitenoise = Whitenoise::get_instance()?;
+    let pubkey = PublicKey::parse(&account_pubkey)?;
+    let member_pubkey = PublicKey::parse(&member_pubkey)?;
+    let group_id = group_id_from_string(&group_id)?;
+    let account = whitenoise.find_account_by_pubkey(&pubkey).await?;
+
+    let mut admins = whitenoise.group_admins(&account, &group_id).await?;
+    ensure_group_admin(&admins, &pubkey)?;
+    let members = whitenoise.group_members(&account, &group_id).await?;
+    if !members.contains(&member_pubkey) {
+        return Err(ApiError::NotGroupMember {
+            message: format!("{} is not a member of this group", member_pubkey.to_hex()),
+        });
+    }
+    if admins.contains(&member_pubkey) {
+        return Ok(());
+    }
+    admins.push(member_pubkey);
+
+    whitenoise
+        .update_group_data(&account, &group_id, admins_update(admins))
+        .await
+        .map_err(ApiError::from)
+}
+
+/// Removes admin rights from a group admin.
+///
+/// The calling account must be an admin of the group. Admins may demote
+/// themselves, but the last admin of a group cannot be demoted.
+#[frb]
+pub async fn demote_admin(
+    account_pubkey: String,
+    group_id: String,
+    admin_pubkey: String,
+) -> Result<(), ApiError> {
+    let whitenoise = Whitenoise::get_instance()?;
+    let pubkey = PublicKey::parse(&account_pubkey)?;
+    let admin_pubkey = PublicKey::parse(&admin_pubkey)?;
+    let group_id = group_id_from_string(&group_id)?;
+    let account = whitenoise.find_account_by_pubkey(&pubkey).await?;
+
+    let admins = whitenoise.group_admins(&account, &group_id).await?;
+    ensure_group_admin(&admins, &pubkey)?;
+    let admins = admins_without(&admins, &admin_pubkey)?;
+
+    whitenoise
+        .update_group_data(&account, &group_id, admins_update(admins))
+        .await
+        .map_err(ApiError::from)
+}
+
 #[frb]
 pub async fn get_group(account_pubkey: String, group_id: String) -> Result<Group, ApiError> {
     let whitenoise = Whitenoise::get_instance()?;
@@ -369,22 +1019,30 @@ pub struct UploadGroupImageResult {
     pub image_nonce: [u8; 12],
 }
 
+/// Sets the avatar of a group.
+///
+/// Encrypts the image following MIP-01, uploads it to the Blossom server at
+/// `server_url` (the default Blossom server when `None`) and commits the new
+/// image key, hash and nonce to the group. The calling account must be an
+/// admin of the group.
 #[frb]
 pub async fn upload_group_image(
     account_pubkey: String,
     group_id: String,
     file_path: String,
-    server_url: String,
+    server_url: Option<String>,
 ) -> Result<UploadGroupImageResult, ApiError> {
     let whitenoise = Whitenoise::get_instance()?;
     let pubkey = PublicKey::parse(&account_pubkey)?;
     let group_id = group_id_from_string(&group_id)?;
     let account = whitenoise.find_account_by_pubkey(&pubkey).await?;
-    let server = Url::parse(&server_url)?;
+    let server = Url::parse(&server_url.unwrap_or_else(get_default_blossom_server_url))?;
 
-    let (encrypted_hash, image_key, image_nonce) = whitenoise
-        .upload_group_image(&account, &group_id, &file_path, Some(server), None)
-        .await?;
+    let admins = whitenoise.group_admins(&account, &group_id).await?;
+    ensure_group_admin(&admins, &pubkey)?;
+
+    let (encrypted_hash, image_key, image_nonce) =
+        set_group_image(whitenoise, &account, &group_id, &file_path, server).await?;
 
     Ok(UploadGroupImageResult {
         encrypted_hash,
@@ -393,6 +1051,40 @@ pub async fn upload_group_image(
     })
 }
 
+/// Uploads an image and commits it as the group avatar.
+async fn set_group_image(
+    whitenoise: &Whitenoise,
+    account: &WhitenoiseAccount,
+    group_id: &GroupId,
+    file_path: &str,
+    server: Url,
+) -> Result<([u8; 32], [u8; 32], [u8; 12]), ApiError> {
+    let (encrypted_hash, image_key, image_nonce) = whitenoise
+        .upload_group_image(account, group_id, file_path, Some(server), None)
+        .await?;
+
+    let image_update = NostrGroupDataUpdate {
+        name: None,
+        description: None,
+        image_key: Some(Some(image_key)),
+        image_hash: Some(Some(encrypted_hash)),
+        image_nonce: Some(Some(image_nonce)),
+        image_upload_key: None,
+        nostr_group_id: None,
+        relays: None,
+        admins: None,
+    };
+    whitenoise
+        .update_group_data(account, group_id, image_update)
+        .await?;
+
+    Ok((encrypted_hash, image_key, image_nonce))
+}
+
+/// Resolves the local path of a group's avatar.
+///
+/// Downloads and decrypts the image into the local media cache when it is not
+/// cached yet. Returns `None` when the group has no image.
 #[frb]
 pub async fn get_group_image_path(
     account_pubkey: String,
@@ -405,3 +1097,170 @@ pub async fn get_group_image_path(
     let path = whitenoise.get_group_image_path(&account, &group_id).await?;
     Ok(path.map(|p| p.to_string_lossy().to_string()))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn keys(count: usize) -> Vec<PublicKey> {
+        (0..count).map(|_| Keys::generate().public_key()).collect()
+    }
+
+    #[test]
+    fn test_ensure_group_admin() {
+        let admins = keys(2);
+        assert!(ensure_group_admin(&admins, &admins[0]).is_ok());
+        let outsider = keys(1)[0];
+        assert!(matches!(
+            ensure_group_admin(&admins, &outsider),
+            Err(ApiError::NotGroupAdmin { .. })
+        ));
+    }
+
+    #[test]
+    fn test_admins_without_removes_admin() {
+        let admins = keys(2);
+        let remaining = admins_without(&admins, &admins[0]).unwrap();
+        assert_eq!(remaining, vec![admins[1]]);
+    }
+
+    #[test]
+    fn test_admins_without_refuses_last_admin() {
+        let admins = keys(1);
+        assert!(matches!(
+            admins_without(&admins, &admins[0]),
+            Err(ApiError::LastGroupAdmin { .. })
+        ));
+    }
+
+    fn direct_message(mls_group_id: &str, created_at: i64) -> ChatSummary {
+        ChatSummary {
+            group_type: GroupType::DirectMessage,
+            created_at: DateTime::from_timestamp(created_at, 0).unwrap(),
+            ..ChatSummary::for_test(mls_group_id)
+        }
+    }
+
+    #[test]
+    fn test_canonical_direct_message_prefers_smallest_group_id() {
+        let candidates = vec![direct_message("bb", 10), direct_message("aa", 20)];
+        let chosen = canonical_direct_message(&candidates).unwrap();
+        assert_eq!(chosen.mls_group_id, "aa");
+    }
+
+    #[test]
+    fn test_canonical_direct_message_none_when_empty() {
+        assert!(canonical_direct_message(&[]).is_none());
+    }
+
+    fn system_event(secs: i64, event: SystemEvent) -> (DateTime<Utc>, SystemEvent) {
+        (DateTime::from_timestamp(secs, 0).unwrap(), event)
+    }
+
+    #[test]
+    fn test_member_additions_keeps_latest_addition() {
+        let system_events = vec![
+            system_event(
+                10,
+                SystemEvent::MembersAdded {
+                    actor: "alice".to_string(),
+                    members: vec!["bob".to_string(), "carol".to_string()],
+                },
+            ),
+            system_event(
+                20,
+                SystemEvent::MembersRemoved {
+                    actor: "alice".to_string(),
+                    members: vec!["bob".to_string()],
+                },
+            ),
+            system_event(
+                30,
+                SystemEvent::MembersAdded {
+                    actor: "carol".to_string(),
+                    members: vec!["bob".to_string()],
+                },
+            ),
+        ];
+        let additions = member_additions(&system_events);
+        assert_eq!(
+            additions["bob"],
+            (
+                DateTime::from_timestamp(30, 0).unwrap(),
+                "carol".to_string()
+            )
+        );
+        assert_eq!(
+            additions["carol"],
+            (
+                DateTime::from_timestamp(10, 0).unwrap(),
+                "alice".to_string()
+            )
+        );
+        assert!(!additions.contains_key("alice"));
+    }
+
+    #[test]
+    fn test_group_state_filter_matches() {
+        assert!(GroupStateFilter::Active.matches(&GroupState::Active));
+        assert!(!GroupStateFilter::Active.matches(&GroupState::Inactive));
+        assert!(GroupStateFilter::Inactive.matches(&GroupState::Inactive));
+        assert!(!GroupStateFilter::Inactive.matches(&GroupState::Pending));
+        assert!(GroupStateFilter::Pending.matches(&GroupState::Pending));
+        assert!(GroupStateFilter::All.matches(&GroupState::Inactive));
+    }
+
+    #[test]
+    fn test_partition_invitable_reports_unready_members() {
+        let ready = keys(1)[0];
+        let checks = vec![
+            InviteCheck {
+                pubkey: ready.to_hex(),
+                status: InviteStatus::Ready,
+            },
+            InviteCheck {
+                pubkey: "not-a-pubkey".to_string(),
+                status: InviteStatus::InvalidPubkey,
+            },
+            InviteCheck {
+                pubkey: keys(1)[0].to_hex(),
+                status: InviteStatus::ExpiredKeyPackage,
+            },
+        ];
+        let (invitable, skipped) = partition_invitable(checks).unwrap();
+        assert_eq!(invitable, vec![ready]);
+        assert_eq!(skipped.len(), 2);
+        assert_eq!(skipped[0].status, InviteStatus::InvalidPubkey);
+        assert_eq!(skipped[1].status, InviteStatus::ExpiredKeyPackage);
+    }
+
+    #[test]
+    fn test_group_data_update_rejects_invalid_admin() {
+        let update = FlutterGroupDataUpdate {
+            name: None,
+            description: None,
+            relays: None,
+            admins: Some(vec!["not-a-pubkey".to_string()]),
+            image_key: None,
+            image_hash: None,
+            image_nonce: None,
+        };
+        let result: Result<NostrGroupDataUpdate, ApiError> = update.try_into();
+        assert!(matches!(result, Err(ApiError::InvalidKey { .. })));
+    }
+
+    #[test]
+    fn test_group_data_update_rejects_empty_admins() {
+        let update = FlutterGroupDataUpdate {
+            name: None,
+            description: None,
+            relays: None,
+            admins: Some(vec![]),
+            image_key: None,
+            image_hash: None,
+            image_nonce: None,
+        };
+        let result: Result<NostrGroupDataUpdate, ApiError> = update.try_into();
+        assert!(matches!(result, Err(ApiError::LastGroupAdmin { .. })));
+    }
+}
@@ -0,0 +1,299 @@
+use crate::api::error::ApiError;
+use crate::api::groups::ensure_group_admin;
+use crate::api::utils::{group_id_from_string, group_id_to_string, timestamp_to_datetime};
+use crate::frb_generated::StreamSink;
+use chrono::{DateTime, Utc};
+use flutter_rust_bridge::frb;
+use mdk_core::prelude::GroupId;
+use nostr_sdk::prelude::*;
+use std::time::Duration;
+use whitenoise::{
+    Account as WhitenoiseAccount, GroupInviteLink as WhitenoiseGroupInviteLink,
+    JoinRequest as WhitenoiseJoinRequest, JoinRequestTrigger as WhitenoiseJoinRequestTrigger,
+    JoinRequestUpdate as WhitenoiseJoinRequestUpdate, Whitenoise,
+};
+
+/// URI scheme of invite links
+const INVITE_LINK_PREFIX: &str = "nostr:";
+
+/// A shareable link inviting anyone who holds it to request joining a group.
+#[frb(non_opaque)]
+#[derive(Debug, Clone)]
+pub struct GroupInviteLink {
+    /// `nostr:` URI carrying the group id, group relays and a signed token
+    pub link: String,
+    pub mls_group_id: String,
+    /// After this time join requests made with the link are rejected
+    pub expires_at: DateTime<Utc>,
+}
+
+impl From<WhitenoiseGroupInviteLink> for GroupInviteLink {
+    fn from(invite: WhitenoiseGroupInviteLink) -> Self {
+        Self {
+            link: invite.link,
+            mls_group_id: group_id_to_string(&invite.mls_group_id),
+            expires_at: timestamp_to_datetime(invite.expires_at),
+        }
+    }
+}
+
+/// A request to join a group made with an invite link.
+#[frb(non_opaque)]
+#[derive(Debug, Clone)]
+pub struct JoinRequest {
+    /// Id (hex) of the join request event
+    pub id: String,
+    pub mls_group_id: String,
+    /// Public key (hex) of the user asking to join
+    pub requester_pubkey: String,
+    pub requested_at: DateTime<Utc>,
+}
+
+impl From<WhitenoiseJoinRequest> for JoinRequest {
+    fn from(request: WhitenoiseJoinRequest) -> Self {
+        Self {
+            id: request.id.to_hex(),
+            mls_group_id: group_id_to_string(&request.mls_group_id),
+            requester_pubkey: request.requester.to_hex(),
+            requested_at: timestamp_to_datetime(request.created_at),
+        }
+    }
+}
+
+/// What happened to a join request.
+#[frb]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum JoinRequestTrigger {
+    /// A new join request arrived
+    Received,
+    /// An admin approved the request and the requester was added
+    Approved,
+    /// An admin denied the request
+    Denied,
+}
+
+impl From<WhitenoiseJoinRequestTrigger> for JoinRequestTrigger {
+    fn from(trigger: WhitenoiseJoinRequestTrigger) -> Self {
+        match trigger {
+            WhitenoiseJoinRequestTrigger::Received => Self::Received,
+            WhitenoiseJoinRequestTrigger::Approved => Self::Approved,
+            WhitenoiseJoinRequestTrigger::Denied => Self::Denied,
+        }
+    }
+}
+
+#[frb(non_opaque)]
+#[derive(Debug, Clone)]
+pub struct JoinRequestUpdate {
+    pub trigger: JoinRequestTrigger,
+    pub request: JoinRequest,
+}
+
+impl From<WhitenoiseJoinRequestUpdate> for JoinRequestUpdate {
+    fn from(update: WhitenoiseJoinRequestUpdate) -> Self {
+        Self {
+            trigger: update.trigger.into(),
+            request: update.request.into(),
+        }
+    }
+}
+
+/// Stream item emitted by `subscribe_to_join_requests`.
+///
+/// The first item is always `InitialSnapshot` containing the open requests.
+/// Subsequent items are `Update` containing new and resolved requests.
+#[frb]
+#[derive(Debug, Clone)]
+pub enum JoinRequestStreamItem {
+    /// Requests waiting for an admin decision at subscription time
+    InitialSnapshot { requests: Vec<JoinRequest> },
+    /// A request arrived or was resolved
+    Update { update: JoinRequestUpdate },
+}
+
+/// Ensures `link` looks like an invite link before handing it to whitenoise.
+fn validate_invite_link(link: &str) -> Result<(), ApiError> {
+    match link.trim().strip_prefix(INVITE_LINK_PREFIX) {
+        Some(rest) if !rest.is_empty() => Ok(()),
+        _ => Err(ApiError::Other {
+            message: "Invalid invite link".to_string(),
+        }),
+    }
+}
+
+/// Loads the account and checks that it administers the group.
+async fn admin_account(
+    whitenoise: &Whitenoise,
+    account_pubkey: &str,
+    group_id: &str,
+) -> Result<(WhitenoiseAccount, GroupId), ApiError> {
+    let pubkey = PublicKey::parse(account_pubkey)?;
+    let account = whitenoise.find_account_by_pubkey(&pubkey).await?;
+    let group_id = group_id_from_string(group_id)?;
+    let admins = whitenoise.group_admins(&account, &group_id).await?;
+    ensure_group_admin(&admins, &pubkey)?;
+    Ok((account, group_id))
+}
+
+/// Creates an invite link for a group. Only admins can create links.
+///
+/// The link carries the group id, the group relays and a token signed by the
+/// admin that expires after `expires_in_secs`.
+#[frb]
+pub async fn create_group_invite_link(
+    account_pubkey: String,
+    group_id: String,
+    expires_in_secs: u64,
+) -> Result<GroupInviteLink, ApiError> {
+    if expires_in_secs == 0 {
+        return Err(ApiError::Other {
+            message: "Invite link lifetime must be greater than zero".to_string(),
+        });
+    }
+    let whitenoise = Whitenoise::get_instance()?;
+    let (account, group_id) = admin_account(whitenoise, &account_pubkey, &group_id).await?;
+    let invite = whitenoise
+        .create_group_invite_link(&account, &group_id, Duration::from_secs(expires_in_secs))
+        .await?;
+    Ok(invite.into())
+}
+
+/// Asks to join the group of an invite link.
+///
+/// Publishes a join request carrying the account's key package to the group
+/// relays. The account is added once an admin approves the request and then
+/// receives the group welcome like any other invite.
+#[frb]
+pub async fn request_join_with_invite(
+    account_pubkey: String,
+    link: String,
+) -> Result<(), ApiError> {
+    validate_invite_link(&link)?;
+    let whitenoise = Whitenoise::get_instance()?;
+    let pubkey = PublicKey::parse(&account_pubkey)?;
+    let account = whitenoise.find_account_by_pubkey(&pubkey).await?;
+    whitenoise
+        .request_join_with_invite(&account, link.trim())
+        .await
+        .map_err(ApiError::from)
+}
+
+/// Subscribe to join requests of a group. Only admins can subscribe.
+///
+/// The stream first emits an `InitialSnapshot` with the open requests, then
+/// `Update` items when requests arrive or are approved or denied by any admin.
+#[frb]
+pub async fn subscribe_to_join_requests(
+    account_pubkey: String,
+    group_id: String,
+    sink: StreamSink<JoinRequestStreamItem>,
+) -> Result<(), ApiError> {
+    let whitenoise = Whitenoise::get_instance()?;
+    let (account, group_id) = admin_account(whitenoise, &account_pubkey, &group_id).await?;
+
+    let subscription = whitenoise
+        .subscribe_to_join_requests(&account, &group_id)
+        .await?;
+
+    // Emit initial snapshot first
+    let requests: Vec<JoinRequest> = subscription
+        .initial_requests
+        .into_iter()
+        .map(|r| r.into())
+        .collect();
+
+    if sink
+        .add(JoinRequestStreamItem::InitialSnapshot { requests })
+        .is_err()
+    {
+        return Ok(()); // Sink closed, exit gracefully
+    }
+
+    // Stream real-time updates
+    let mut rx = subscription.updates;
+    loop {
+        match rx.recv().await {
+            Ok(update) => {
+                let item = JoinRequestStreamItem::Update {
+                    update: update.into(),
+                };
+                if sink.add(item).is_err() {
+                    break; // Sink closed
+                }
+            }
+            Err(tokio::sync::broadcast::error::RecvError::Lagged(_)) => {
+                // Slow consumer missed some updates - each update carries the
+                // complete request, resubscribe for a fresh snapshot if needed
+                continue;
+            }
+            Err(tokio::sync::broadcast::error::RecvError::Closed) => {
+                break; // Channel closed
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Approves a join request, adding the requester to the group.
+#[frb]
+pub async fn approve_join_request(
+    account_pubkey: String,
+    group_id: String,
+    request_id: String,
+) -> Result<(), ApiError> {
+    let whitenoise = Whitenoise::get_instance()?;
+    let (account, group_id) = admin_account(whitenoise, &account_pubkey, &group_id).await?;
+    let request_id = EventId::from_hex(&request_id)?;
+    whitenoise
+        .approve_join_request(&account, &group_id, &request_id)
+        .await
+        .map_err(ApiError::from)
+}
+
+/// Denies a join request. The requester is not notified.
+#[frb]
+pub async fn deny_join_request(
+    account_pubkey: String,
+    group_id: String,
+    request_id: String,
+) -> Result<(), ApiError> {
+    let whitenoise = Whitenoise::get_instance()?;
+    let (account, group_id) = admin_account(whitenoise, &account_pubkey, &group_id).await?;
+    let request_id = EventId::from_hex(&request_id)?;
+    whitenoise
+        .deny_join_request(&account, &group_id, &request_id)
+        .await
+        .map_err(ApiError::from)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_join_request_trigger_conversion_received() {
+        let trigger: JoinRequestTrigger = WhitenoiseJoinRequestTrigger::Received.into();
+        assert_eq!(trigger, JoinRequestTrigger::Received);
+    }
+
+    #[test]
+    fn test_join_request_trigger_conversion_approved() {
+        let trigger: JoinRequestTrigger = WhitenoiseJoinRequestTrigger::Approved.into();
+        assert_eq!(trigger, JoinRequestTrigger::Approved);
+    }
+
+    #[test]
+    fn test_join_request_trigger_conversion_denied() {
+        let trigger: JoinRequestTrigger = WhitenoiseJoinRequestTrigger::Denied.into();
+        assert_eq!(trigger, JoinRequestTrigger::Denied);
+    }
+
+    #[test]
+    fn test_validate_invite_link() {
+        assert!(validate_invite_link("nostr:naddr1invite").is_ok());
+        assert!(validate_invite_link("  nostr:naddr1invite\n").is_ok());
+        assert!(validate_invite_link("nostr:").is_err());
+        assert!(validate_invite_link("https://example.com").is_err());
+    }
+}
@@ -0,0 +1,254 @@
+use crate::api::chat_list::ChatSummary;
+use crate::api::error::ApiError;
+use crate::api::groups::GroupType;
+use crate::api::users::User;
+use crate::api::utils::group_id_from_string;
+use crate::frb_generated::StreamSink;
+use chrono::{DateTime, Utc};
+use flutter_rust_bridge::frb;
+use nostr_sdk::PublicKey;
+use std::collections::HashSet;
+use whitenoise::{
+    Account as WhitenoiseAccount, AccountGroup as WhitenoiseAccountGroup, UserSyncMode, Whitenoise,
+};
+
+/// A group invite waiting for the user to accept or decline it.
+#[frb(non_opaque)]
+#[derive(Debug, Clone)]
+pub struct PendingInvite {
+    /// MLS group identifier (hex string), used with `accept_account_group`
+    /// and `decline_account_group`
+    pub mls_group_id: String,
+    pub group_type: GroupType,
+    pub group_name: String,
+    pub group_description: String,
+    /// Path to cached decrypted group image (None if no image or not downloaded yet)
+    pub group_image_path: Option<String>,
+    /// The user who sent the welcome (None if unknown)
+    pub inviter: Option<User>,
+    pub member_count: u64,
+    /// Public keys (hex) of the group admins
+    pub admin_pubkeys: Vec<String>,
+    /// When the welcome was received
+    pub received_at: DateTime<Utc>,
+}
+
+/// Stream item emitted by `subscribe_to_pending_invites`.
+///
+/// The first item is always `InitialSnapshot` containing the current invites.
+/// Subsequent items signal invites arriving or being resolved.
+#[frb]
+#[derive(Debug, Clone)]
+pub enum PendingInviteStreamItem {
+    /// Pending invites at subscription time
+    InitialSnapshot { invites: Vec<PendingInvite> },
+    /// A new invite was received
+    InviteReceived { invite: PendingInvite },
+    /// An invite was accepted or declined
+    InviteResolved { mls_group_id: String },
+}
+
+/// How a chat list change affects the set of pending invites.
+#[derive(Debug, PartialEq, Eq)]
+enum InviteChange {
+    Received,
+    Resolved,
+}
+
+/// Updates the tracked pending invites with the latest state of a chat.
+fn track_invite(pending: &mut HashSet<String>, chat: &ChatSummary) -> Option<InviteChange> {
+    if chat.pending_confirmation {
+        pending
+            .insert(chat.mls_group_id.clone())
+            .then_some(InviteChange::Received)
+    } else {
+        pending
+            .remove(&chat.mls_group_id)
+            .then_some(InviteChange::Resolved)
+    }
+}
+
+/// Loads the invite details of a pending chat.
+async fn pending_invite(
+    whitenoise: &Whitenoise,
+    account: &WhitenoiseAccount,
+    chat: ChatSummary,
+) -> Result<PendingInvite, ApiError> {
+    let group_id = group_id_from_string(&chat.mls_group_id)?;
+    let group = whitenoise.group(account, &group_id).await?;
+    let member_count = whitenoise.group_members(account, &group_id).await?.len();
+
+    let inviter = match chat.welcomer_pubkey.as_deref() {
+        Some(welcomer) => {
+            let welcomer = PublicKey::parse(welcomer)?;
+            let user = whitenoise
+                .find_or_create_user_by_pubkey(&welcomer, UserSyncMode::Background)
+                .await?;
+            Some(user.into())
+        }
+        None => None,
+    };
+
+    let received_at = WhitenoiseAccountGroup::get(whitenoise, &account.pubkey, &group_id)
+        .await?
+        .map(|ag| ag.created_at)
+        .unwrap_or(chat.created_at);
+
+    Ok(PendingInvite {
+        mls_group_id: chat.mls_group_id,
+        group_type: chat.group_type,
+        group_name: group.name,
+        group_description: group.description,
+        group_image_path: chat.group_image_path,
+        inviter,
+        member_count: member_count as u64,
+        admin_pubkeys: group.admin_pubkeys.iter().map(|pk| pk.to_hex()).collect(),
+        received_at,
+    })
+}
+
+/// Returns the group invites the account has not accepted or declined yet,
+/// newest first.
+///
+/// Invites whose details cannot be loaded are left out.
+#[frb]
+pub async fn pending_invites(account_pubkey: String) -> Result<Vec<PendingInvite>, ApiError> {
+    let whitenoise = Whitenoise::get_instance()?;
+    let pubkey = PublicKey::parse(&account_pubkey)?;
+    let account = whitenoise.find_account_by_pubkey(&pubkey).await?;
+
+    let mut invites = Vec::new();
+    for chat in whitenoise.get_chat_list(&account).await? {
+        let chat = ChatSummary::from(chat);
+        if chat.pending_confirmation
+            && let Ok(invite) = pending_invite(whitenoise, &account, chat).await
+        {
+            invites.push(invite);
+        }
+    }
+    invites.sort_by(|a, b| b.received_at.cmp(&a.received_at));
+    Ok(invites)
+}
+
+/// Subscribe to the pending invites of an account.
+///
+/// The stream first emits an `InitialSnapshot` with the current invites, then
+/// `InviteReceived` when a welcome arrives and `InviteResolved` when an invite
+/// is accepted or declined. Use it to drive an invite badge.
+///
+/// Invites whose details cannot be loaded are skipped and retried on the next
+/// update of their chat.
+#[frb]
+pub async fn subscribe_to_pending_invites(
+    account_pubkey: String,
+    sink: StreamSink<PendingInviteStreamItem>,
+) -> Result<(), ApiError> {
+    let whitenoise = Whitenoise::get_instance()?;
+    let pubkey = PublicKey::parse(&account_pubkey)?;
+    let account = whitenoise.find_account_by_pubkey(&pubkey).await?;
+
+    let subscription = whitenoise.subscribe_to_chat_list(&account).await?;
+
+    // Emit initial snapshot first
+    let mut pending = HashSet::new();
+    let mut invites = Vec::new();
+    for chat in subscription.initial_items {
+        let chat = ChatSummary::from(chat);
+        if track_invite(&mut pending, &chat).is_some() {
+            let mls_group_id = chat.mls_group_id.clone();
+            match pending_invite(whitenoise, &account, chat).await {
+                Ok(invite) => invites.push(invite),
+                Err(_) => {
+                    pending.remove(&mls_group_id);
+                }
+            }
+        }
+    }
+    invites.sort_by(|a, b| b.received_at.cmp(&a.received_at));
+
+    if sink
+        .add(PendingInviteStreamItem::InitialSnapshot { invites })
+        .is_err()
+    {
+        return Ok(()); // Sink closed, exit gracefully
+    }
+
+    // Stream real-time updates
+    let mut rx = subscription.updates;
+    loop {
+        match rx.recv().await {
+            Ok(update) => {
+                let chat = ChatSummary::from(update.item);
+                let item = match track_invite(&mut pending, &chat) {
+                    Some(InviteChange::Received) => {
+                        let mls_group_id = chat.mls_group_id.clone();
+                        match pending_invite(whitenoise, &account, chat).await {
+                            Ok(invite) => PendingInviteStreamItem::InviteReceived { invite },
+                            Err(_) => {
+                                // Untrack so the next update of the chat retries
+                                pending.remove(&mls_group_id);
+                                continue;
+                            }
+                        }
+                    }
+                    Some(InviteChange::Resolved) => PendingInviteStreamItem::InviteResolved {
+                        mls_group_id: chat.mls_group_id,
+                    },
+                    None => continue,
+                };
+                if sink.add(item).is_err() {
+                    break; // Sink closed
+                }
+            }
+            Err(tokio::sync::broadcast::error::RecvError::Lagged(_)) => {
+                // Slow consumer missed some updates - pending state is
+                // corrected by the next update of each affected chat
+                continue;
+            }
+            Err(tokio::sync::broadcast::error::RecvError::Closed) => {
+                break; // Channel closed
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn chat(mls_group_id: &str, pending_confirmation: bool) -> ChatSummary {
+        ChatSummary {
+            pending_confirmation,
+            ..ChatSummary::for_test(mls_group_id)
+        }
+    }
+
+    #[test]
+    fn track_invite_reports_new_invites_once() {
+        let mut pending = HashSet::new();
+        assert_eq!(
+            track_invite(&mut pending, &chat("aa", true)),
+            Some(InviteChange::Received)
+        );
+        assert_eq!(track_invite(&mut pending, &chat("aa", true)), None);
+    }
+
+    #[test]
+    fn track_invite_reports_resolution_of_tracked_invites() {
+        let mut pending = HashSet::new();
+        track_invite(&mut pending, &chat("aa", true));
+        assert_eq!(
+            track_invite(&mut pending, &chat("aa", false)),
+            Some(InviteChange::Resolved)
+        );
+        assert!(pending.is_empty());
+    }
+
+    #[test]
+    fn track_invite_ignores_accepted_chats() {
+        let mut pending = HashSet::new();
+        assert_eq!(track_invite(&mut pending, &chat("bb", false)), None);
+    }
+}
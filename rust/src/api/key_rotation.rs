@@ -0,0 +1,138 @@
+use crate::api::error::ApiError;
+use crate::api::utils::{group_id_from_string, group_id_to_string};
+use chrono::{DateTime, Utc};
+use flutter_rust_bridge::frb;
+use mdk_core::prelude::GroupId;
+use nostr_sdk::PublicKey;
+use whitenoise::{
+    AccountGroup as WhitenoiseAccountGroup, KeyRotationPolicy as WhitenoiseKeyRotationPolicy,
+    Whitenoise,
+};
+
+/// When the account's leaf key is rotated automatically in each group.
+///
+/// A rotation happens as soon as either limit is reached. With both limits
+/// unset, keys are only rotated through `rotate_group_keys`.
+#[frb(non_opaque)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeyRotationPolicy {
+    /// Rotate when the last rotation is older than this many days
+    pub after_days: Option<u32>,
+    /// Rotate after this many messages were sent by the account since the last rotation
+    pub after_messages: Option<u32>,
+}
+
+impl From<WhitenoiseKeyRotationPolicy> for KeyRotationPolicy {
+    fn from(policy: WhitenoiseKeyRotationPolicy) -> Self {
+        Self {
+            after_days: policy.after_days,
+            after_messages: policy.after_messages,
+        }
+    }
+}
+
+impl TryFrom<KeyRotationPolicy> for WhitenoiseKeyRotationPolicy {
+    type Error = ApiError;
+
+    fn try_from(policy: KeyRotationPolicy) -> Result<Self, Self::Error> {
+        if policy.after_days == Some(0) || policy.after_messages == Some(0) {
+            return Err(ApiError::Other {
+                message: "Key rotation limits must be greater than zero".to_string(),
+            });
+        }
+        Ok(Self {
+            after_days: policy.after_days,
+            after_messages: policy.after_messages,
+        })
+    }
+}
+
+/// Key rotation state of the account in a group.
+#[frb(non_opaque)]
+#[derive(Debug, Clone)]
+pub struct GroupKeyRotation {
+    pub mls_group_id: String,
+    /// Current MLS epoch of the group
+    pub epoch: u64,
+    /// When the account last rotated its leaf key (None if never rotated)
+    pub last_rotated_at: Option<DateTime<Utc>>,
+}
+
+async fn group_key_rotation_for(
+    whitenoise: &Whitenoise,
+    pubkey: &PublicKey,
+    group_id: &GroupId,
+) -> Result<GroupKeyRotation, ApiError> {
+    let account = whitenoise.find_account_by_pubkey(pubkey).await?;
+    let group = whitenoise.group(&account, group_id).await?;
+    let last_rotated_at = WhitenoiseAccountGroup::get(whitenoise, pubkey, group_id)
+        .await?
+        .and_then(|ag| ag.last_key_rotation_at);
+    Ok(GroupKeyRotation {
+        mls_group_id: group_id_to_string(group_id),
+        epoch: group.epoch,
+        last_rotated_at,
+    })
+}
+
+/// Rotates the account's leaf key in a group with an MLS self-update commit.
+///
+/// Rotating keys regularly gives post-compromise security: a leaked key stops
+/// decrypting new messages after the next rotation. Returns the new epoch and
+/// rotation time.
+#[frb]
+pub async fn rotate_group_keys(
+    account_pubkey: String,
+    group_id: String,
+) -> Result<GroupKeyRotation, ApiError> {
+    let whitenoise = Whitenoise::get_instance()?;
+    let pubkey = PublicKey::parse(&account_pubkey)?;
+    let account = whitenoise.find_account_by_pubkey(&pubkey).await?;
+    let group_id = group_id_from_string(&group_id)?;
+    whitenoise.rotate_group_keys(&account, &group_id).await?;
+    group_key_rotation_for(whitenoise, &pubkey, &group_id).await
+}
+
+/// Returns the current epoch and last key rotation time of the account in a group.
+#[frb]
+pub async fn group_key_rotation(
+    account_pubkey: String,
+    group_id: String,
+) -> Result<GroupKeyRotation, ApiError> {
+    let whitenoise = Whitenoise::get_instance()?;
+    let pubkey = PublicKey::parse(&account_pubkey)?;
+    let group_id = group_id_from_string(&group_id)?;
+    group_key_rotation_for(whitenoise, &pubkey, &group_id).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_key_rotation_policy_rejects_zero_limits() {
+        let policy = KeyRotationPolicy {
+            after_days: Some(0),
+            after_messages: None,
+        };
+        let result: Result<WhitenoiseKeyRotationPolicy, ApiError> = policy.try_into();
+        assert!(result.is_err());
+
+        let policy = KeyRotationPolicy {
+            after_days: None,
+            after_messages: Some(0),
+        };
+        let result: Result<WhitenoiseKeyRotationPolicy, ApiError> = policy.try_into();
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_key_rotation_policy_round_trip() {
+        let policy = KeyRotationPolicy {
+            after_days: Some(30),
+            after_messages: Some(500),
+        };
+        let converted: WhitenoiseKeyRotationPolicy = policy.clone().try_into().unwrap();
+        assert_eq!(KeyRotationPolicy::from(converted), policy);
+    }
+}
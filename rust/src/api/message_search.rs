@@ -0,0 +1,338 @@
+use crate::api::error::ApiError;
+use crate::api::messages::{CHAT_MESSAGE_KIND, ChatMessage};
+use crate::api::utils::{group_id_from_string, group_id_to_string, timestamp_to_datetime};
+use crate::frb_generated::StreamSink;
+use chrono::{DateTime, Utc};
+use flutter_rust_bridge::frb;
+use nostr_sdk::PublicKey;
+use whitenoise::{SerializableToken as WhitenoiseSerializableToken, Whitenoise};
+
+/// Position after which a search continues.
+///
+/// Groups are searched in ascending group id order and messages within a
+/// group from newest to oldest.
+#[frb(non_opaque)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MessageSearchCursor {
+    pub mls_group_id: String,
+    pub message_id: String,
+    /// Creation time of the cursor message, used to resume when the message
+    /// no longer exists
+    pub created_at: DateTime<Utc>,
+}
+
+/// A matched range inside one of the message's `content_tokens`.
+///
+/// `start` and `end` are character offsets into the token content.
+#[frb(non_opaque)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HighlightRange {
+    pub token_index: u64,
+    pub start: u64,
+    pub end: u64,
+}
+
+#[frb(non_opaque)]
+#[derive(Debug, Clone)]
+pub struct MessageSearchResult {
+    pub mls_group_id: String,
+    pub message: ChatMessage,
+    pub highlights: Vec<HighlightRange>,
+}
+
+#[frb]
+#[derive(Debug, Clone)]
+pub enum MessageSearchTrigger {
+    /// New hits were found in a group
+    ResultsFound,
+    /// The search finished. `next_cursor` is set when `limit` was reached
+    /// and more results may be available.
+    SearchCompleted {
+        next_cursor: Option<MessageSearchCursor>,
+    },
+}
+
+#[frb(non_opaque)]
+#[derive(Debug, Clone)]
+pub struct MessageSearchUpdate {
+    pub trigger: MessageSearchTrigger,
+    pub new_results: Vec<MessageSearchResult>,
+    pub total_result_count: u64,
+}
+
+/// Content of the tokens matched against the search query: text, hashtags and URLs.
+fn searchable_content(token: &WhitenoiseSerializableToken) -> Option<&str> {
+    match token {
+        WhitenoiseSerializableToken::Text(s)
+        | WhitenoiseSerializableToken::Hashtag(s)
+        | WhitenoiseSerializableToken::Url(s) => Some(s),
+        _ => None,
+    }
+}
+
+/// Finds every occurrence of the query words in the searchable tokens.
+///
+/// Matching is case-insensitive and every word of the query must occur in the
+/// message. Returns an empty list when the message does not match.
+fn highlight_ranges(tokens: &[WhitenoiseSerializableToken], query: &str) -> Vec<HighlightRange> {
+    let fold = |text: &str| -> Vec<char> {
+        // Map each char to a single lowercase char so offsets stay aligned
+        text.chars()
+            .map(|c| c.to_lowercase().next().unwrap_or(c))
+            .collect()
+    };
+    let words: Vec<Vec<char>> = query.split_whitespace().map(fold).collect();
+    if words.is_empty() {
+        return vec![];
+    }
+
+    let mut ranges = Vec::new();
+    let mut matched_words = vec![false; words.len()];
+    for (token_index, token) in tokens.iter().enumerate() {
+        let Some(content) = searchable_content(token) else {
+            continue;
+        };
+        let content = fold(content);
+        for (word_index, word) in words.iter().enumerate() {
+            for start in 0..content.len().saturating_sub(word.len() - 1) {
+                if content[start..start + word.len()] == word[..] {
+                    matched_words[word_index] = true;
+                    ranges.push(HighlightRange {
+                        token_index: token_index as u64,
+                        start: start as u64,
+                        end: (start + word.len()) as u64,
+                    });
+                }
+            }
+        }
+    }
+
+    if matched_words.iter().all(|matched| *matched) {
+        ranges.sort_by_key(|r| (r.token_index, r.start));
+        ranges
+    } else {
+        vec![]
+    }
+}
+
+/// Returns the index in a newest-first message list after which a search
+/// continues from `cursor`.
+///
+/// When the cursor message is gone, e.g. deleted since the previous page, the
+/// search continues with the messages created before it.
+fn resume_index(newest_first: &[(&str, DateTime<Utc>)], cursor: &MessageSearchCursor) -> usize {
+    newest_first
+        .iter()
+        .position(|(id, _)| *id == cursor.message_id)
+        .map(|index| index + 1)
+        .unwrap_or_else(|| {
+            newest_first
+                .iter()
+                .position(|(_, created_at)| *created_at < cursor.created_at)
+                .unwrap_or(newest_first.len())
+        })
+}
+
+/// Searches the decrypted messages of the account's groups.
+///
+/// Results are streamed group by group as `ResultsFound` updates, followed by
+/// a final `SearchCompleted`. At most `limit` results are returned; pass the
+/// `next_cursor` of the completion update to fetch the next results.
+///
+/// When `group_id` is set only that group is searched.
+#[frb]
+pub async fn search_messages(
+    account_pubkey: String,
+    query: String,
+    group_id: Option<String>,
+    limit: u32,
+    cursor: Option<MessageSearchCursor>,
+    sink: StreamSink<MessageSearchUpdate>,
+) -> Result<(), ApiError> {
+    if query.trim().is_empty() {
+        return Err(ApiError::Other {
+            message: "Search query must not be empty".to_string(),
+        });
+    }
+
+    let whitenoise = Whitenoise::get_instance()?;
+    let pubkey = PublicKey::parse(&account_pubkey)?;
+    let account = whitenoise.find_account_by_pubkey(&pubkey).await?;
+
+    let mut group_ids = match group_id {
+        Some(group_id) => vec![group_id],
+        None => whitenoise
+            .groups(&account, true)
+            .await?
+            .iter()
+            .map(|group| group_id_to_string(&group.mls_group_id))
+            .collect(),
+    };
+    group_ids.sort();
+    if let Some(cursor) = &cursor {
+        group_ids.retain(|id| *id >= cursor.mls_group_id);
+    }
+
+    let limit = limit as usize;
+    let mut total_results = 0usize;
+    let mut next_cursor = None;
+
+    for group_id in group_ids {
+        if total_results >= limit {
+            break;
+        }
+        let mls_group_id = group_id_from_string(&group_id)?;
+        let messages = whitenoise
+            .fetch_aggregated_messages_for_group(&pubkey, &mls_group_id)
+            .await?;
+
+        // Resume right after the cursor message when continuing in its group
+        let skip = match cursor.as_ref().filter(|c| c.mls_group_id == group_id) {
+            Some(cursor) => {
+                let newest_first: Vec<(&str, DateTime<Utc>)> = messages
+                    .iter()
+                    .rev()
+                    .map(|m| (m.id.as_str(), timestamp_to_datetime(m.created_at)))
+                    .collect();
+                resume_index(&newest_first, cursor)
+            }
+            None => 0,
+        };
+
+        let mut new_results = Vec::new();
+        for message in messages.iter().rev().skip(skip) {
+            if message.is_deleted || message.kind != CHAT_MESSAGE_KIND {
+                continue;
+            }
+            // Match on the core tokens so only hits are converted
+            let highlights = highlight_ranges(&message.content_tokens, &query);
+            if highlights.is_empty() {
+                continue;
+            }
+            new_results.push(MessageSearchResult {
+                mls_group_id: group_id.clone(),
+                message: message.into(),
+                highlights,
+            });
+            if total_results + new_results.len() >= limit {
+                break;
+            }
+        }
+
+        if new_results.is_empty() {
+            continue;
+        }
+        total_results += new_results.len();
+        if total_results >= limit {
+            next_cursor = new_results.last().map(|result| MessageSearchCursor {
+                mls_group_id: group_id.clone(),
+                message_id: result.message.id.clone(),
+                created_at: result.message.created_at,
+            });
+        }
+
+        let update = MessageSearchUpdate {
+            trigger: MessageSearchTrigger::ResultsFound,
+            new_results,
+            total_result_count: total_results as u64,
+        };
+        if sink.add(update).is_err() {
+            return Ok(()); // Sink closed
+        }
+    }
+
+    let _ = sink.add(MessageSearchUpdate {
+        trigger: MessageSearchTrigger::SearchCompleted { next_cursor },
+        new_results: vec![],
+        total_result_count: total_results as u64,
+    });
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn text(content: &str) -> WhitenoiseSerializableToken {
+        WhitenoiseSerializableToken::Text(content.to_string())
+    }
+
+    #[test]
+    fn highlight_ranges_matches_case_insensitively() {
+        let tokens = vec![text("Hello"), WhitenoiseSerializableToken::Whitespace];
+        let ranges = highlight_ranges(&tokens, "hELLo");
+        assert_eq!(
+            ranges,
+            vec![HighlightRange {
+                token_index: 0,
+                start: 0,
+                end: 5,
+            }]
+        );
+    }
+
+    #[test]
+    fn highlight_ranges_requires_every_word() {
+        let tokens = vec![
+            text("meeting"),
+            WhitenoiseSerializableToken::Whitespace,
+            text("tomorrow"),
+        ];
+        assert_eq!(highlight_ranges(&tokens, "meet morrow").len(), 2);
+        assert!(highlight_ranges(&tokens, "meeting today").is_empty());
+    }
+
+    #[test]
+    fn highlight_ranges_ignores_non_searchable_tokens() {
+        let tokens = vec![WhitenoiseSerializableToken::Nostr("npub1hello".to_string())];
+        assert!(highlight_ranges(&tokens, "hello").is_empty());
+    }
+
+    fn cursor(message_id: &str, secs: i64) -> MessageSearchCursor {
+        MessageSearchCursor {
+            mls_group_id: "aa".to_string(),
+            message_id: message_id.to_string(),
+            created_at: DateTime::from_timestamp(secs, 0).unwrap(),
+        }
+    }
+
+    fn newest_first() -> Vec<(&'static str, DateTime<Utc>)> {
+        [("m3", 30), ("m2", 20), ("m1", 10)]
+            .into_iter()
+            .map(|(id, secs)| (id, DateTime::from_timestamp(secs, 0).unwrap()))
+            .collect()
+    }
+
+    #[test]
+    fn resume_index_continues_after_cursor_message() {
+        assert_eq!(resume_index(&newest_first(), &cursor("m2", 20)), 2);
+    }
+
+    #[test]
+    fn resume_index_falls_back_to_cursor_timestamp() {
+        assert_eq!(resume_index(&newest_first(), &cursor("gone", 25)), 1);
+        assert_eq!(resume_index(&newest_first(), &cursor("gone", 5)), 3);
+    }
+
+    #[test]
+    fn highlight_ranges_uses_char_offsets() {
+        let tokens = vec![text("¡Olé olé!")];
+        let ranges = highlight_ranges(&tokens, "olé");
+        assert_eq!(
+            ranges,
+            vec![
+                HighlightRange {
+                    token_index: 0,
+                    start: 1,
+                    end: 4,
+                },
+                HighlightRange {
+                    token_index: 0,
+                    start: 5,
+                    end: 8,
+                },
+            ]
+        );
+    }
+}
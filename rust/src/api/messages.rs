@@ -1,18 +1,25 @@
 use crate::api::{
     error::ApiError,
     media_files::MediaFile,
-    utils::{group_id_from_string, group_id_to_string},
+    outbox::OutboxMessage,
+    utils::{group_id_from_string, group_id_to_string, timestamp_to_datetime},
 };
 use crate::frb_generated::StreamSink;
-use chrono::{DateTime, TimeZone, Utc};
+use chrono::{DateTime, Utc};
 use flutter_rust_bridge::frb;
+use mdk_core::prelude::GroupId;
 use nostr_sdk::prelude::*;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{LazyLock, Mutex};
+use whitenoise::OutboxMessage as WhitenoiseOutboxMessage;
 use whitenoise::whitenoise::message_aggregator::ChatMessageSummary as WhitenoiseChatMessageSummary;
 pub use whitenoise::{
     ChatMessage as WhitenoiseChatMessage, EmojiReaction as WhitenoiseEmojiReaction,
-    MediaFile as WhitenoiseMediaFile, MessageUpdate as WhitenoiseMessageUpdate,
-    MessageWithTokens as WhitenoiseMessageWithTokens, ReactionSummary as WhitenoiseReactionSummary,
-    SerializableToken as WhitenoiseSerializableToken, UpdateTrigger as WhitenoiseUpdateTrigger,
+    MediaFile as WhitenoiseMediaFile, MessageEdit as WhitenoiseMessageEdit,
+    MessageUpdate as WhitenoiseMessageUpdate, MessageWithTokens as WhitenoiseMessageWithTokens,
+    ReactionSummary as WhitenoiseReactionSummary, SerializableToken as WhitenoiseSerializableToken,
+    SystemEvent as WhitenoiseSystemEvent, UpdateTrigger as WhitenoiseUpdateTrigger,
     UserReaction as WhitenoiseUserReaction, Whitenoise,
 };
 
@@ -44,6 +51,50 @@ pub struct ChatMessage {
     pub reactions: ReactionSummary,
     pub media_attachments: Vec<MediaFile>,
     pub kind: u16,
+    /// When the message was last edited (None if never edited)
+    pub edited_at: Option<DateTime<Utc>>,
+    /// Previous versions of the message, oldest first
+    pub edit_history: Vec<MessageEdit>,
+    /// Set for system timeline entries synthesized from group commits
+    /// (member adds/removes, renames, admin changes). These entries use their
+    /// own `kind`, are never published and have an empty `content`.
+    pub system_event: Option<SystemEvent>,
+}
+
+/// Flutter-compatible group change shown as a system entry in the timeline.
+///
+/// Public keys are hex strings; `actor` is the member who made the commit.
+#[frb]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SystemEvent {
+    /// "Alice added Bob"
+    MembersAdded { actor: String, members: Vec<String> },
+    /// "Alice removed Bob"
+    MembersRemoved { actor: String, members: Vec<String> },
+    /// "Bob left the group"
+    MemberLeft { actor: String },
+    /// "Carol renamed the group to ..."
+    GroupRenamed { actor: String, name: String },
+    /// "Carol changed the group description"
+    DescriptionChanged { actor: String, description: String },
+    /// "Carol changed the group image"
+    ImageChanged { actor: String },
+    /// "Alice made Bob an admin" / "Alice removed Bob as admin"
+    AdminsChanged {
+        actor: String,
+        added: Vec<String>,
+        removed: Vec<String>,
+    },
+}
+
+/// Flutter-compatible previous version of an edited message
+#[frb(non_opaque)]
+#[derive(Debug, Clone)]
+pub struct MessageEdit {
+    /// Id of the event that carried this version of the content
+    pub event_id: String,
+    pub content: String,
+    pub created_at: DateTime<Utc>,
 }
 
 /// Flutter-compatible reaction summary
@@ -104,6 +155,8 @@ pub enum UpdateTrigger {
     ReactionRemoved,
     /// The message itself was marked as deleted
     MessageDeleted,
+    /// The message content was replaced by an edit
+    MessageEdited,
 }
 
 /// A real-time update for a group message.
@@ -119,15 +172,55 @@ pub struct MessageUpdate {
 
 /// Stream item emitted by `subscribe_to_group_messages`.
 ///
-/// The first item is always `InitialSnapshot` containing all current messages.
-/// Subsequent items are `Update` containing real-time changes.
+/// The first item is always `InitialSnapshot` containing the newest messages.
+/// Subsequent items are `Update` containing real-time changes, `OutboxUpdate`
+/// items for queued outgoing messages, and `OlderPage` items requested through
+/// `load_older_group_messages`.
 #[frb]
 #[derive(Debug, Clone)]
 pub enum MessageStreamItem {
-    /// Initial snapshot of all messages in the group at subscription time
-    InitialSnapshot { messages: Vec<ChatMessage> },
+    /// Initial snapshot of the newest messages in the group at subscription time
+    InitialSnapshot {
+        /// Identifier used to request older pages on this stream
+        subscription_id: String,
+        messages: Vec<ChatMessage>,
+        /// Whether older messages can be requested with `load_older_group_messages`
+        has_more_before: bool,
+    },
     /// Real-time update for a single message
     Update { update: MessageUpdate },
+    /// Delivery status change of a message queued with `queue_message_to_group`
+    OutboxUpdate { message: OutboxMessage },
+    /// A page of messages older than everything previously emitted on this stream
+    OlderPage {
+        messages: Vec<ChatMessage>,
+        has_more_before: bool,
+    },
+}
+
+/// Position in a group's message history to page from.
+#[frb]
+#[derive(Debug, Clone)]
+pub enum MessageCursor {
+    /// Messages strictly older than the message with this id
+    BeforeMessage { message_id: String },
+    /// Messages strictly newer than the message with this id
+    AfterMessage { message_id: String },
+    /// Messages created strictly before this timestamp
+    BeforeTimestamp { timestamp: DateTime<Utc> },
+    /// Messages created strictly after this timestamp
+    AfterTimestamp { timestamp: DateTime<Utc> },
+}
+
+/// A page of aggregated messages, ordered oldest first.
+#[frb(non_opaque)]
+#[derive(Debug, Clone)]
+pub struct MessagePage {
+    pub messages: Vec<ChatMessage>,
+    /// Whether older messages exist before this page
+    pub has_more_before: bool,
+    /// Whether newer messages exist after this page
+    pub has_more_after: bool,
 }
 
 // From implementations to convert from Whitenoise types to Flutter-compatible types
@@ -145,13 +238,7 @@ impl From<&WhitenoiseMessageWithTokens> for MessageWithTokens {
             id: message_with_tokens.message.id.to_hex(),
             pubkey: message_with_tokens.message.pubkey.to_hex(),
             kind: message_with_tokens.message.kind.as_u16(),
-            created_at: {
-                let ts =
-                    i64::try_from(message_with_tokens.message.created_at.as_secs()).unwrap_or(0);
-                Utc.timestamp_opt(ts, 0)
-                    .single()
-                    .unwrap_or_else(|| Utc.timestamp_opt(0, 0).single().unwrap())
-            },
+            created_at: timestamp_to_datetime(message_with_tokens.message.created_at),
             content: Some(message_with_tokens.message.content.clone()),
             tokens,
         }
@@ -233,12 +320,7 @@ impl From<&WhitenoiseReactionSummary> for ReactionSummary {
                 reaction_id: user_reaction.reaction_id.to_hex(),
                 user: user_reaction.user.to_hex(),
                 emoji: user_reaction.emoji.clone(),
-                created_at: {
-                    let ts = i64::try_from(user_reaction.created_at.as_secs()).unwrap_or(0);
-                    Utc.timestamp_opt(ts, 0)
-                        .single()
-                        .unwrap_or_else(|| Utc.timestamp_opt(0, 0).single().unwrap())
-                },
+                created_at: timestamp_to_datetime(user_reaction.created_at),
             })
             .collect();
 
@@ -255,6 +337,57 @@ impl From<WhitenoiseReactionSummary> for ReactionSummary {
     }
 }
 
+impl From<&WhitenoiseMessageEdit> for MessageEdit {
+    fn from(edit: &WhitenoiseMessageEdit) -> Self {
+        Self {
+            event_id: edit.event_id.to_hex(),
+            content: edit.content.clone(),
+            created_at: timestamp_to_datetime(edit.created_at),
+        }
+    }
+}
+
+impl From<&WhitenoiseSystemEvent> for SystemEvent {
+    fn from(event: &WhitenoiseSystemEvent) -> Self {
+        let to_hex = |pubkeys: &[PublicKey]| pubkeys.iter().map(|pk| pk.to_hex()).collect();
+        match event {
+            WhitenoiseSystemEvent::MembersAdded { actor, members } => Self::MembersAdded {
+                actor: actor.to_hex(),
+                members: to_hex(members),
+            },
+            WhitenoiseSystemEvent::MembersRemoved { actor, members } => Self::MembersRemoved {
+                actor: actor.to_hex(),
+                members: to_hex(members),
+            },
+            WhitenoiseSystemEvent::MemberLeft { actor } => Self::MemberLeft {
+                actor: actor.to_hex(),
+            },
+            WhitenoiseSystemEvent::GroupRenamed { actor, name } => Self::GroupRenamed {
+                actor: actor.to_hex(),
+                name: name.clone(),
+            },
+            WhitenoiseSystemEvent::DescriptionChanged { actor, description } => {
+                Self::DescriptionChanged {
+                    actor: actor.to_hex(),
+                    description: description.clone(),
+                }
+            }
+            WhitenoiseSystemEvent::ImageChanged { actor } => Self::ImageChanged {
+                actor: actor.to_hex(),
+            },
+            WhitenoiseSystemEvent::AdminsChanged {
+                actor,
+                added,
+                removed,
+            } => Self::AdminsChanged {
+                actor: actor.to_hex(),
+                added: to_hex(added),
+                removed: to_hex(removed),
+            },
+        }
+    }
+}
+
 impl From<&WhitenoiseChatMessage> for ChatMessage {
     fn from(chat_message: &WhitenoiseChatMessage) -> Self {
         let tags = chat_message
@@ -277,12 +410,7 @@ impl From<&WhitenoiseChatMessage> for ChatMessage {
             id: chat_message.id.clone(),
             pubkey: chat_message.author.to_hex(),
             content: chat_message.content.clone(),
-            created_at: {
-                let ts = i64::try_from(chat_message.created_at.as_secs()).unwrap_or(0);
-                Utc.timestamp_opt(ts, 0)
-                    .single()
-                    .unwrap_or_else(|| Utc.timestamp_opt(0, 0).single().unwrap())
-            },
+            created_at: timestamp_to_datetime(chat_message.created_at),
             tags,
             is_reply: chat_message.is_reply,
             reply_to_id: chat_message.reply_to_id.clone(),
@@ -296,6 +424,13 @@ impl From<&WhitenoiseChatMessage> for ChatMessage {
                 .map(|media_file| media_file.into())
                 .collect(),
             kind: chat_message.kind,
+            edited_at: chat_message.edited_at.map(timestamp_to_datetime),
+            edit_history: chat_message
+                .edit_history
+                .iter()
+                .map(|edit| edit.into())
+                .collect(),
+            system_event: chat_message.system_event.as_ref().map(|event| event.into()),
         }
     }
 }
@@ -306,6 +441,33 @@ impl From<WhitenoiseChatMessage> for ChatMessage {
     }
 }
 
+#[cfg(test)]
+impl ChatMessage {
+    /// Plain chat message without content, created `secs` after the Unix epoch.
+    pub(crate) fn for_test(id: &str, secs: i64) -> Self {
+        Self {
+            id: id.to_string(),
+            pubkey: String::new(),
+            content: String::new(),
+            created_at: DateTime::from_timestamp(secs, 0).unwrap(),
+            tags: vec![],
+            is_reply: false,
+            reply_to_id: None,
+            is_deleted: false,
+            content_tokens: vec![],
+            reactions: ReactionSummary {
+                by_emoji: vec![],
+                user_reactions: vec![],
+            },
+            media_attachments: vec![],
+            kind: CHAT_MESSAGE_KIND,
+            edited_at: None,
+            edit_history: vec![],
+            system_event: None,
+        }
+    }
+}
+
 impl From<WhitenoiseUpdateTrigger> for UpdateTrigger {
     fn from(trigger: WhitenoiseUpdateTrigger) -> Self {
         match trigger {
@@ -313,6 +475,7 @@ impl From<WhitenoiseUpdateTrigger> for UpdateTrigger {
             WhitenoiseUpdateTrigger::ReactionAdded => Self::ReactionAdded,
             WhitenoiseUpdateTrigger::ReactionRemoved => Self::ReactionRemoved,
             WhitenoiseUpdateTrigger::MessageDeleted => Self::MessageDeleted,
+            WhitenoiseUpdateTrigger::MessageEdited => Self::MessageEdited,
         }
     }
 }
@@ -332,6 +495,58 @@ impl From<WhitenoiseMessageUpdate> for MessageUpdate {
     }
 }
 
+/// Kind used for chat messages and replies
+pub(crate) const CHAT_MESSAGE_KIND: u16 = 9;
+/// Kind used for reactions (NIP-25)
+const REACTION_KIND: u16 = 7;
+/// Kind used for deletions (NIP-09)
+const DELETION_KIND: u16 = 5;
+/// Kind used for edits replacing the content of a chat message
+const EDIT_KIND: u16 = 1010;
+
+/// Builds the `e`, `p` and `k` tags referencing a target event.
+///
+/// Reactions, replies and deletions all reference their target with this
+/// same set of tags.
+fn event_reference_tags(event_id: &str, author: &str, kind: u16) -> Result<Vec<Tag>, ApiError> {
+    let event_id = EventId::from_hex(event_id)?;
+    let author = PublicKey::parse(author)?;
+    Ok(vec![
+        Tag::parse(["e", event_id.to_hex().as_str()])?,
+        Tag::parse(["p", author.to_hex().as_str(), ""])?,
+        Tag::parse(["k", kind.to_string().as_str()])?,
+    ])
+}
+
+/// Validates the emoji content of a reaction.
+fn validate_reaction_emoji(emoji: &str) -> Result<(), ApiError> {
+    if emoji.trim().is_empty() {
+        return Err(ApiError::Other {
+            message: "Reaction emoji must not be empty".to_string(),
+        });
+    }
+    Ok(())
+}
+
+/// Looks up a message in the aggregated store of a group.
+async fn find_aggregated_message(
+    whitenoise: &Whitenoise,
+    pubkey: &PublicKey,
+    group_id: &GroupId,
+    message_id: &str,
+) -> Result<WhitenoiseChatMessage, ApiError> {
+    whitenoise
+        .fetch_aggregated_messages_for_group(pubkey, group_id)
+        .await?
+        .into_iter()
+        .find(|m| m.id == message_id)
+        // System entries are synthesized from commits and cannot be referenced
+        .filter(|m| m.system_event.is_none())
+        .ok_or_else(|| ApiError::Other {
+            message: format!("Message {message_id} not found in group"),
+        })
+}
+
 #[frb]
 pub async fn send_message_to_group(
     pubkey: String,
@@ -350,6 +565,269 @@ pub async fn send_message_to_group(
     Ok((&message_with_tokens).into())
 }
 
+/// Sends a reply to a message in a group.
+///
+/// The author and kind of the replied-to message are resolved from the
+/// aggregated message store.
+#[frb]
+pub async fn send_reply(
+    pubkey: String,
+    group_id: String,
+    message_id: String,
+    message: String,
+) -> Result<MessageWithTokens, ApiError> {
+    let whitenoise = Whitenoise::get_instance()?;
+    let pubkey = PublicKey::parse(&pubkey)?;
+    let account = whitenoise.find_account_by_pubkey(&pubkey).await?;
+    let group_id = group_id_from_string(&group_id)?;
+    let target = find_aggregated_message(whitenoise, &pubkey, &group_id, &message_id).await?;
+    let tags = event_reference_tags(&target.id, &target.author.to_hex(), target.kind)?;
+    let message_with_tokens = whitenoise
+        .send_message_to_group(&account, &group_id, message, CHAT_MESSAGE_KIND, Some(tags))
+        .await?;
+    Ok((&message_with_tokens).into())
+}
+
+/// Reacts to a message in a group with an emoji.
+#[frb]
+pub async fn send_reaction(
+    pubkey: String,
+    group_id: String,
+    message_id: String,
+    emoji: String,
+) -> Result<MessageWithTokens, ApiError> {
+    validate_reaction_emoji(&emoji)?;
+    let whitenoise = Whitenoise::get_instance()?;
+    let pubkey = PublicKey::parse(&pubkey)?;
+    let account = whitenoise.find_account_by_pubkey(&pubkey).await?;
+    let group_id = group_id_from_string(&group_id)?;
+    let target = find_aggregated_message(whitenoise, &pubkey, &group_id, &message_id).await?;
+    let tags = event_reference_tags(&target.id, &target.author.to_hex(), target.kind)?;
+    let message_with_tokens = whitenoise
+        .send_message_to_group(&account, &group_id, emoji, REACTION_KIND, Some(tags))
+        .await?;
+    Ok((&message_with_tokens).into())
+}
+
+/// Removes the account's reaction with the given emoji from a message.
+///
+/// Publishes a deletion for the matching reaction event.
+#[frb]
+pub async fn remove_reaction(
+    pubkey: String,
+    group_id: String,
+    message_id: String,
+    emoji: String,
+) -> Result<MessageWithTokens, ApiError> {
+    let whitenoise = Whitenoise::get_instance()?;
+    let pubkey = PublicKey::parse(&pubkey)?;
+    let account = whitenoise.find_account_by_pubkey(&pubkey).await?;
+    let group_id = group_id_from_string(&group_id)?;
+    let target = find_aggregated_message(whitenoise, &pubkey, &group_id, &message_id).await?;
+    let reaction = target
+        .reactions
+        .user_reactions
+        .iter()
+        .find(|r| r.user == pubkey && r.emoji == emoji)
+        .ok_or_else(|| ApiError::Other {
+            message: format!("No {emoji} reaction from this account on message {message_id}"),
+        })?;
+    let tags = event_reference_tags(
+        &reaction.reaction_id.to_hex(),
+        &pubkey.to_hex(),
+        REACTION_KIND,
+    )?;
+    let message_with_tokens = whitenoise
+        .send_message_to_group(
+            &account,
+            &group_id,
+            String::new(),
+            DELETION_KIND,
+            Some(tags),
+        )
+        .await?;
+    Ok((&message_with_tokens).into())
+}
+
+/// Edits one of the account's own chat messages in a group.
+///
+/// Publishes a replacement event referencing the original message. Once
+/// aggregated, the original message carries the new content, `edited_at` and
+/// its previous versions in `edit_history`, and subscribers receive an
+/// `UpdateTrigger::MessageEdited` update.
+#[frb]
+pub async fn edit_message(
+    pubkey: String,
+    group_id: String,
+    message_id: String,
+    new_content: String,
+) -> Result<MessageWithTokens, ApiError> {
+    if new_content.trim().is_empty() {
+        return Err(ApiError::Other {
+            message: "Edited message content must not be empty".to_string(),
+        });
+    }
+    let whitenoise = Whitenoise::get_instance()?;
+    let pubkey = PublicKey::parse(&pubkey)?;
+    let account = whitenoise.find_account_by_pubkey(&pubkey).await?;
+    let group_id = group_id_from_string(&group_id)?;
+    let target = find_aggregated_message(whitenoise, &pubkey, &group_id, &message_id).await?;
+    if target.author != pubkey {
+        return Err(ApiError::Other {
+            message: format!("Message {message_id} was not sent by this account"),
+        });
+    }
+    if target.is_deleted || target.kind != CHAT_MESSAGE_KIND {
+        return Err(ApiError::Other {
+            message: format!("Message {message_id} cannot be edited"),
+        });
+    }
+    let tags = event_reference_tags(&target.id, &target.author.to_hex(), target.kind)?;
+    let message_with_tokens = whitenoise
+        .send_message_to_group(&account, &group_id, new_content, EDIT_KIND, Some(tags))
+        .await?;
+    Ok((&message_with_tokens).into())
+}
+
+/// Deletes one of the account's own messages in a group.
+#[frb]
+pub async fn delete_message(
+    pubkey: String,
+    group_id: String,
+    message_id: String,
+) -> Result<MessageWithTokens, ApiError> {
+    let whitenoise = Whitenoise::get_instance()?;
+    let pubkey = PublicKey::parse(&pubkey)?;
+    let account = whitenoise.find_account_by_pubkey(&pubkey).await?;
+    let group_id = group_id_from_string(&group_id)?;
+    let target = find_aggregated_message(whitenoise, &pubkey, &group_id, &message_id).await?;
+    if target.author != pubkey {
+        return Err(ApiError::Other {
+            message: format!("Message {message_id} was not sent by this account"),
+        });
+    }
+    let tags = event_reference_tags(&target.id, &target.author.to_hex(), target.kind)?;
+    let message_with_tokens = whitenoise
+        .send_message_to_group(
+            &account,
+            &group_id,
+            String::new(),
+            DELETION_KIND,
+            Some(tags),
+        )
+        .await?;
+    Ok((&message_with_tokens).into())
+}
+
+/// A message that can be located and ordered within a group's history.
+trait PagedMessage: Into<ChatMessage> {
+    fn message_id(&self) -> &str;
+    fn created_at(&self) -> DateTime<Utc>;
+}
+
+impl PagedMessage for WhitenoiseChatMessage {
+    fn message_id(&self) -> &str {
+        &self.id
+    }
+
+    fn created_at(&self) -> DateTime<Utc> {
+        timestamp_to_datetime(self.created_at)
+    }
+}
+
+#[cfg(test)]
+impl PagedMessage for ChatMessage {
+    fn message_id(&self) -> &str {
+        &self.id
+    }
+
+    fn created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+}
+
+/// Slices a chronologically ordered message list into a single page.
+///
+/// Without a cursor the newest `limit` messages are returned. Only the
+/// messages of the page are converted.
+fn paginate_messages<M: PagedMessage>(
+    mut messages: Vec<M>,
+    cursor: Option<&MessageCursor>,
+    limit: usize,
+) -> Result<MessagePage, ApiError> {
+    // Stable sort keeps the aggregator's ordering for messages sharing a timestamp
+    messages.sort_by_key(|m| m.created_at());
+
+    let position_of = |message_id: &str| {
+        messages
+            .iter()
+            .position(|m| m.message_id() == message_id)
+            .ok_or_else(|| ApiError::Other {
+                message: format!("Message {message_id} not found in group"),
+            })
+    };
+
+    let (start, end) = match cursor {
+        None => (messages.len().saturating_sub(limit), messages.len()),
+        Some(MessageCursor::BeforeMessage { message_id }) => {
+            let end = position_of(message_id)?;
+            (end.saturating_sub(limit), end)
+        }
+        Some(MessageCursor::AfterMessage { message_id }) => {
+            let start = position_of(message_id)? + 1;
+            (start, (start + limit).min(messages.len()))
+        }
+        Some(MessageCursor::BeforeTimestamp { timestamp }) => {
+            let end = messages.partition_point(|m| m.created_at() < *timestamp);
+            (end.saturating_sub(limit), end)
+        }
+        Some(MessageCursor::AfterTimestamp { timestamp }) => {
+            let start = messages.partition_point(|m| m.created_at() <= *timestamp);
+            (start, (start + limit).min(messages.len()))
+        }
+    };
+
+    let has_more_before = start > 0;
+    let has_more_after = end < messages.len();
+    messages.truncate(end);
+    let messages = messages.split_off(start);
+
+    Ok(MessagePage {
+        messages: messages.into_iter().map(Into::into).collect(),
+        has_more_before,
+        has_more_after,
+    })
+}
+
+/// Messages of a paged subscription that have not been sent to Dart yet.
+///
+/// They are kept unconverted and refreshed by the subscription's updates, so
+/// older pages always reflect the current state of each message.
+struct PagedSubscription {
+    sink: StreamSink<MessageStreamItem>,
+    older_messages: Vec<WhitenoiseChatMessage>,
+}
+
+/// Replaces the stored copy of an updated message in a paged subscription.
+fn refresh_older_message(subscription_id: &str, message: WhitenoiseChatMessage) {
+    let mut subscriptions = PAGED_SUBSCRIPTIONS.lock().unwrap();
+    let Some(subscription) = subscriptions.get_mut(subscription_id) else {
+        return;
+    };
+    if let Some(stored) = subscription
+        .older_messages
+        .iter_mut()
+        .find(|stored| stored.id == message.id)
+    {
+        *stored = message;
+    }
+}
+
+static NEXT_SUBSCRIPTION_ID: AtomicU64 = AtomicU64::new(1);
+
+static PAGED_SUBSCRIPTIONS: LazyLock<Mutex<HashMap<String, PagedSubscription>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
 #[frb]
 pub async fn fetch_aggregated_messages_for_group(
     pubkey: String,
@@ -364,68 +842,182 @@ pub async fn fetch_aggregated_messages_for_group(
     Ok(messages.into_iter().map(|m| m.into()).collect())
 }
 
+/// Fetches a single page of aggregated messages for a group.
+///
+/// Without a cursor the newest `limit` messages are returned. Messages in the
+/// page are ordered oldest first, matching `fetch_aggregated_messages_for_group`.
+#[frb]
+pub async fn fetch_aggregated_messages_page(
+    pubkey: String,
+    group_id: String,
+    cursor: Option<MessageCursor>,
+    limit: u32,
+) -> Result<MessagePage, ApiError> {
+    let whitenoise = Whitenoise::get_instance()?;
+    let pubkey = PublicKey::parse(&pubkey)?;
+    let group_id = group_id_from_string(&group_id)?;
+    let messages = whitenoise
+        .fetch_aggregated_messages_for_group(&pubkey, &group_id)
+        .await?;
+    paginate_messages(messages, cursor.as_ref(), limit as usize)
+}
+
 /// Subscribe to real-time message updates for a group.
 ///
-/// The stream first emits an `InitialSnapshot` containing all current messages,
-/// then emits `Update` items as messages are added, reacted to, or deleted.
+/// The stream first emits an `InitialSnapshot` containing the newest
+/// `snapshot_limit` messages (all messages when `None`), then emits `Update`
+/// items as messages are added, reacted to, or deleted. Older messages can be
+/// requested on the same stream with `load_older_group_messages`.
 ///
 /// The initial snapshot is race-condition free: any updates that arrive between
 /// subscribing and fetching are merged into the snapshot.
 #[frb]
 pub async fn subscribe_to_group_messages(
     group_id: String,
+    snapshot_limit: Option<u32>,
     sink: StreamSink<MessageStreamItem>,
 ) -> Result<(), ApiError> {
     let whitenoise = Whitenoise::get_instance()?;
     let group_id = group_id_from_string(&group_id)?;
 
     let subscription = whitenoise.subscribe_to_group_messages(&group_id).await?;
+    let outbox_updates = whitenoise.subscribe_to_outbox(&group_id).await?;
 
-    // Emit initial snapshot first
-    let initial_messages: Vec<ChatMessage> = subscription
-        .initial_messages
+    let mut initial_messages = subscription.initial_messages;
+    let split_at = snapshot_limit
+        .map(|limit| initial_messages.len().saturating_sub(limit as usize))
+        .unwrap_or(0);
+    let newest_messages: Vec<ChatMessage> = initial_messages
+        .split_off(split_at)
         .into_iter()
         .map(|m| m.into())
         .collect();
 
+    let subscription_id = NEXT_SUBSCRIPTION_ID
+        .fetch_add(1, Ordering::Relaxed)
+        .to_string();
+    let has_more_before = !initial_messages.is_empty();
+    PAGED_SUBSCRIPTIONS.lock().unwrap().insert(
+        subscription_id.clone(),
+        PagedSubscription {
+            sink: sink.clone(),
+            older_messages: initial_messages,
+        },
+    );
+
+    // Emit initial snapshot first
     if sink
         .add(MessageStreamItem::InitialSnapshot {
-            messages: initial_messages,
+            subscription_id: subscription_id.clone(),
+            messages: newest_messages,
+            has_more_before,
         })
-        .is_err()
+        .is_ok()
     {
-        return Ok(()); // Sink closed, exit gracefully
+        forward_message_updates(
+            &subscription_id,
+            subscription.updates,
+            outbox_updates,
+            &sink,
+        )
+        .await;
     }
 
-    // Stream real-time updates
-    let mut rx = subscription.updates;
+    PAGED_SUBSCRIPTIONS.lock().unwrap().remove(&subscription_id);
+    Ok(())
+}
+
+/// Emits the next page of older messages on a `subscribe_to_group_messages` stream.
+///
+/// The page is delivered as a `MessageStreamItem::OlderPage` on the stream
+/// identified by `subscription_id`, ordered oldest first.
+#[frb]
+pub async fn load_older_group_messages(
+    subscription_id: String,
+    limit: u32,
+) -> Result<(), ApiError> {
+    let (sink, messages, has_more_before) = {
+        let mut subscriptions = PAGED_SUBSCRIPTIONS.lock().unwrap();
+        let subscription =
+            subscriptions
+                .get_mut(&subscription_id)
+                .ok_or_else(|| ApiError::Other {
+                    message: format!("Message subscription {subscription_id} not found"),
+                })?;
+        let split_at = subscription
+            .older_messages
+            .len()
+            .saturating_sub(limit as usize);
+        let messages: Vec<ChatMessage> = subscription
+            .older_messages
+            .split_off(split_at)
+            .into_iter()
+            .map(|m| m.into())
+            .collect();
+        (
+            subscription.sink.clone(),
+            messages,
+            !subscription.older_messages.is_empty(),
+        )
+    };
+
+    sink.add(MessageStreamItem::OlderPage {
+        messages,
+        has_more_before,
+    })
+    .map_err(|e| ApiError::Other {
+        message: e.to_string(),
+    })
+}
+
+async fn forward_message_updates(
+    subscription_id: &str,
+    mut rx: tokio::sync::broadcast::Receiver<WhitenoiseMessageUpdate>,
+    mut outbox_rx: tokio::sync::broadcast::Receiver<WhitenoiseOutboxMessage>,
+    sink: &StreamSink<MessageStreamItem>,
+) {
+    use tokio::sync::broadcast::error::RecvError;
+
+    // Stream real-time updates; message updates keep flowing if the outbox
+    // channel closes first
+    let mut outbox_open = true;
     loop {
-        match rx.recv().await {
-            Ok(update) => {
-                let item = MessageStreamItem::Update {
-                    update: update.into(),
-                };
-                if sink.add(item).is_err() {
-                    break; // Sink closed
+        let item = tokio::select! {
+            result = rx.recv() => match result {
+                Ok(update) => {
+                    let item = MessageStreamItem::Update {
+                        update: (&update).into(),
+                    };
+                    refresh_older_message(subscription_id, update.message);
+                    item
                 }
-            }
-            Err(tokio::sync::broadcast::error::RecvError::Lagged(_)) => {
                 // Slow consumer missed some updates - safe to continue since
                 // each update contains the complete message state
-                continue;
-            }
-            Err(tokio::sync::broadcast::error::RecvError::Closed) => {
-                break; // Channel closed
-            }
+                Err(RecvError::Lagged(_)) => continue,
+                Err(RecvError::Closed) => break, // Channel closed
+            },
+            result = outbox_rx.recv(), if outbox_open => match result {
+                Ok(message) => MessageStreamItem::OutboxUpdate {
+                    message: message.into(),
+                },
+                // Each outbox update carries the full current status
+                Err(RecvError::Lagged(_)) => continue,
+                Err(RecvError::Closed) => {
+                    outbox_open = false;
+                    continue;
+                }
+            },
+        };
+        if sink.add(item).is_err() {
+            break; // Sink closed
         }
     }
-
-    Ok(())
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use chrono::TimeZone;
 
     #[test]
     fn test_update_trigger_conversion_new_message() {
@@ -450,4 +1042,145 @@ mod tests {
         let trigger: UpdateTrigger = WhitenoiseUpdateTrigger::MessageDeleted.into();
         assert_eq!(trigger, UpdateTrigger::MessageDeleted);
     }
+
+    #[test]
+    fn test_update_trigger_conversion_message_edited() {
+        let trigger: UpdateTrigger = WhitenoiseUpdateTrigger::MessageEdited.into();
+        assert_eq!(trigger, UpdateTrigger::MessageEdited);
+    }
+
+    #[test]
+    fn test_system_event_conversion_members_added() {
+        let actor = Keys::generate().public_key();
+        let member = Keys::generate().public_key();
+        let event: SystemEvent = (&WhitenoiseSystemEvent::MembersAdded {
+            actor,
+            members: vec![member],
+        })
+            .into();
+        assert_eq!(
+            event,
+            SystemEvent::MembersAdded {
+                actor: actor.to_hex(),
+                members: vec![member.to_hex()],
+            }
+        );
+    }
+
+    #[test]
+    fn test_system_event_conversion_group_renamed() {
+        let actor = Keys::generate().public_key();
+        let event: SystemEvent = (&WhitenoiseSystemEvent::GroupRenamed {
+            actor,
+            name: "Book club".to_string(),
+        })
+            .into();
+        assert_eq!(
+            event,
+            SystemEvent::GroupRenamed {
+                actor: actor.to_hex(),
+                name: "Book club".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn test_event_reference_tags() {
+        let event_id = "a".repeat(64);
+        let author = "79be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798".to_string();
+        let tags = event_reference_tags(&event_id, &author, 9).unwrap();
+        let tags: Vec<Vec<String>> = tags.iter().map(|t| t.as_slice().to_vec()).collect();
+        assert_eq!(
+            tags,
+            vec![
+                vec!["e".to_string(), event_id],
+                vec!["p".to_string(), author, String::new()],
+                vec!["k".to_string(), "9".to_string()],
+            ]
+        );
+    }
+
+    #[test]
+    fn test_event_reference_tags_rejects_invalid_event_id() {
+        assert!(
+            event_reference_tags(
+                "not-an-id",
+                "79be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798",
+                9
+            )
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn test_validate_reaction_emoji() {
+        assert!(validate_reaction_emoji("👍").is_ok());
+        assert!(validate_reaction_emoji("+").is_ok());
+        assert!(validate_reaction_emoji("  ").is_err());
+    }
+
+    fn history() -> Vec<ChatMessage> {
+        (1..=5)
+            .map(|i| ChatMessage::for_test(&format!("m{i}"), i * 10))
+            .collect()
+    }
+
+    fn ids(page: &MessagePage) -> Vec<&str> {
+        page.messages.iter().map(|m| m.id.as_str()).collect()
+    }
+
+    #[test]
+    fn test_paginate_messages_without_cursor_returns_newest() {
+        let page = paginate_messages(history(), None, 2).unwrap();
+        assert_eq!(ids(&page), vec!["m4", "m5"]);
+        assert!(page.has_more_before);
+        assert!(!page.has_more_after);
+    }
+
+    #[test]
+    fn test_paginate_messages_before_message() {
+        let cursor = MessageCursor::BeforeMessage {
+            message_id: "m4".to_string(),
+        };
+        let page = paginate_messages(history(), Some(&cursor), 2).unwrap();
+        assert_eq!(ids(&page), vec!["m2", "m3"]);
+        assert!(page.has_more_before);
+        assert!(page.has_more_after);
+    }
+
+    #[test]
+    fn test_paginate_messages_after_message() {
+        let cursor = MessageCursor::AfterMessage {
+            message_id: "m3".to_string(),
+        };
+        let page = paginate_messages(history(), Some(&cursor), 10).unwrap();
+        assert_eq!(ids(&page), vec!["m4", "m5"]);
+        assert!(page.has_more_before);
+        assert!(!page.has_more_after);
+    }
+
+    #[test]
+    fn test_paginate_messages_by_timestamp() {
+        let before = MessageCursor::BeforeTimestamp {
+            timestamp: Utc.timestamp_opt(30, 0).single().unwrap(),
+        };
+        let page = paginate_messages(history(), Some(&before), 10).unwrap();
+        assert_eq!(ids(&page), vec!["m1", "m2"]);
+        assert!(!page.has_more_before);
+
+        let after = MessageCursor::AfterTimestamp {
+            timestamp: Utc.timestamp_opt(30, 0).single().unwrap(),
+        };
+        let page = paginate_messages(history(), Some(&after), 1).unwrap();
+        assert_eq!(ids(&page), vec!["m4"]);
+        assert!(page.has_more_after);
+    }
+
+    #[test]
+    fn test_paginate_messages_unknown_message_id() {
+        let cursor = MessageCursor::BeforeMessage {
+            message_id: "missing".to_string(),
+        };
+        assert!(paginate_messages(history(), Some(&cursor), 2).is_err());
+    }
 }
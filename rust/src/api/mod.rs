@@ -60,12 +60,20 @@ pub mod account_settings;
 pub mod accounts;
 pub mod chat_list;
 pub mod error;
+pub mod group_updates;
 pub mod groups;
+pub mod invite_links;
+pub mod invites;
+pub mod key_rotation;
 pub mod media_files;
+pub mod message_search;
 pub mod messages;
 pub mod metadata;
+pub mod outbox;
+pub mod read_receipts;
 pub mod relays;
 pub mod signer;
+pub mod typing;
 pub mod user_search;
 pub mod users;
 pub mod utils;
@@ -76,12 +84,20 @@ pub use account_settings::*;
 pub use accounts::*;
 pub use chat_list::*;
 pub use error::*;
+pub use group_updates::*;
 pub use groups::*;
+pub use invite_links::*;
+pub use invites::*;
+pub use key_rotation::*;
 pub use media_files::*;
+pub use message_search::*;
 pub use messages::*;
 pub use metadata::*;
+pub use outbox::*;
+pub use read_receipts::*;
 pub use relays::*;
 pub use signer::*;
+pub use typing::*;
 pub use user_search::*;
 pub use users::*;
 pub use utils::*;
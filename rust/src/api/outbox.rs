@@ -0,0 +1,181 @@
+use crate::api::{
+    error::ApiError,
+    utils::{group_id_from_string, group_id_to_string},
+};
+use chrono::{DateTime, Utc};
+use flutter_rust_bridge::frb;
+use nostr_sdk::prelude::*;
+use whitenoise::{
+    OutboxMessage as WhitenoiseOutboxMessage, OutboxStatus as WhitenoiseOutboxStatus, Whitenoise,
+};
+
+/// Delivery status of a message in the outbox.
+#[frb]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OutboxStatus {
+    /// Waiting to be published (e.g. no relay is reachable yet)
+    Queued,
+    /// Currently being published to the group relays
+    Publishing,
+    /// Published and accepted by `relay_count` relays
+    Published { relay_count: u64 },
+    /// Publishing failed; the message is retried when relays reconnect
+    Failed { reason: String },
+}
+
+impl From<WhitenoiseOutboxStatus> for OutboxStatus {
+    fn from(status: WhitenoiseOutboxStatus) -> Self {
+        match status {
+            WhitenoiseOutboxStatus::Queued => Self::Queued,
+            WhitenoiseOutboxStatus::Publishing => Self::Publishing,
+            WhitenoiseOutboxStatus::Published { relay_count } => Self::Published {
+                relay_count: relay_count as u64,
+            },
+            WhitenoiseOutboxStatus::Failed { reason } => Self::Failed { reason },
+        }
+    }
+}
+
+/// A message waiting in, or sent through, the persistent outbox.
+#[frb(non_opaque)]
+#[derive(Debug, Clone)]
+pub struct OutboxMessage {
+    /// Local identifier assigned when the message is queued
+    pub local_id: String,
+    pub account_pubkey: String,
+    pub mls_group_id: String,
+    pub content: String,
+    pub kind: u16,
+    pub tags: Vec<Vec<String>>,
+    pub status: OutboxStatus,
+    /// Id of the published event (None until publishing succeeds)
+    pub event_id: Option<String>,
+    /// Number of publish attempts so far
+    pub attempts: u32,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<WhitenoiseOutboxMessage> for OutboxMessage {
+    fn from(message: WhitenoiseOutboxMessage) -> Self {
+        Self {
+            local_id: message.local_id,
+            account_pubkey: message.account_pubkey.to_hex(),
+            mls_group_id: group_id_to_string(&message.mls_group_id),
+            content: message.content,
+            kind: message.kind,
+            tags: message
+                .tags
+                .iter()
+                .map(|tag| tag.as_slice().to_vec())
+                .collect(),
+            status: message.status.into(),
+            event_id: message.event_id.map(|id| id.to_hex()),
+            attempts: message.attempts,
+            created_at: message.created_at,
+            updated_at: message.updated_at,
+        }
+    }
+}
+
+/// Queues a message for delivery to a group.
+///
+/// Unlike `send_message_to_group`, this returns as soon as the message is
+/// persisted in the outbox. Publishing happens in the background and is
+/// retried automatically when relays reconnect. Status changes are emitted as
+/// `MessageStreamItem::OutboxUpdate` on `subscribe_to_group_messages`.
+#[frb]
+pub async fn queue_message_to_group(
+    pubkey: String,
+    group_id: String,
+    message: String,
+    kind: u16,
+    tags: Option<Vec<Tag>>,
+) -> Result<OutboxMessage, ApiError> {
+    let whitenoise = Whitenoise::get_instance()?;
+    let pubkey = PublicKey::parse(&pubkey)?;
+    let account = whitenoise.find_account_by_pubkey(&pubkey).await?;
+    let group_id = group_id_from_string(&group_id)?;
+    let outbox_message = whitenoise
+        .queue_message_to_group(&account, &group_id, message, kind, tags)
+        .await?;
+    Ok(outbox_message.into())
+}
+
+/// Returns the outbox messages of a group that are not yet published.
+///
+/// Used to restore pending and failed bubbles when a chat is opened.
+#[frb]
+pub async fn outbox_messages(
+    pubkey: String,
+    group_id: String,
+) -> Result<Vec<OutboxMessage>, ApiError> {
+    let whitenoise = Whitenoise::get_instance()?;
+    let pubkey = PublicKey::parse(&pubkey)?;
+    let account = whitenoise.find_account_by_pubkey(&pubkey).await?;
+    let group_id = group_id_from_string(&group_id)?;
+    let messages = whitenoise.outbox_messages(&account, &group_id).await?;
+    Ok(messages.into_iter().map(|m| m.into()).collect())
+}
+
+/// Retries publishing a queued or failed outbox message immediately.
+#[frb]
+pub async fn retry_outbox_message(
+    pubkey: String,
+    local_id: String,
+) -> Result<OutboxMessage, ApiError> {
+    let whitenoise = Whitenoise::get_instance()?;
+    let pubkey = PublicKey::parse(&pubkey)?;
+    let account = whitenoise.find_account_by_pubkey(&pubkey).await?;
+    let outbox_message = whitenoise.retry_outbox_message(&account, &local_id).await?;
+    Ok(outbox_message.into())
+}
+
+/// Removes a message from the outbox without publishing it.
+#[frb]
+pub async fn discard_outbox_message(pubkey: String, local_id: String) -> Result<(), ApiError> {
+    let whitenoise = Whitenoise::get_instance()?;
+    let pubkey = PublicKey::parse(&pubkey)?;
+    let account = whitenoise.find_account_by_pubkey(&pubkey).await?;
+    whitenoise
+        .discard_outbox_message(&account, &local_id)
+        .await
+        .map_err(ApiError::from)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_outbox_status_conversion_queued() {
+        let status: OutboxStatus = WhitenoiseOutboxStatus::Queued.into();
+        assert_eq!(status, OutboxStatus::Queued);
+    }
+
+    #[test]
+    fn test_outbox_status_conversion_publishing() {
+        let status: OutboxStatus = WhitenoiseOutboxStatus::Publishing.into();
+        assert_eq!(status, OutboxStatus::Publishing);
+    }
+
+    #[test]
+    fn test_outbox_status_conversion_published() {
+        let status: OutboxStatus = WhitenoiseOutboxStatus::Published { relay_count: 3 }.into();
+        assert_eq!(status, OutboxStatus::Published { relay_count: 3 });
+    }
+
+    #[test]
+    fn test_outbox_status_conversion_failed() {
+        let status: OutboxStatus = WhitenoiseOutboxStatus::Failed {
+            reason: "no relays".to_string(),
+        }
+        .into();
+        assert_eq!(
+            status,
+            OutboxStatus::Failed {
+                reason: "no relays".to_string()
+            }
+        );
+    }
+}
@@ -0,0 +1,98 @@
+use crate::api::error::ApiError;
+use crate::api::utils::{group_id_from_string, timestamp_to_datetime};
+use crate::frb_generated::StreamSink;
+use chrono::{DateTime, Utc};
+use flutter_rust_bridge::frb;
+use whitenoise::{ReadReceipt as WhitenoiseReadReceipt, Whitenoise};
+
+/// A group member's read marker.
+///
+/// The reader has read the message `message_id` and every message before it.
+#[frb(non_opaque)]
+#[derive(Debug, Clone)]
+pub struct ReadReceipt {
+    /// Public key (hex) of the member who read the message
+    pub reader: String,
+    /// Id (hex) of the newest message the member has read
+    pub message_id: String,
+    pub read_at: DateTime<Utc>,
+}
+
+impl From<WhitenoiseReadReceipt> for ReadReceipt {
+    fn from(receipt: WhitenoiseReadReceipt) -> Self {
+        Self {
+            reader: receipt.reader.to_hex(),
+            message_id: receipt.message_id.to_hex(),
+            read_at: timestamp_to_datetime(receipt.read_at),
+        }
+    }
+}
+
+/// Stream item emitted by `subscribe_to_read_receipts`.
+///
+/// The first item is always `InitialSnapshot` containing the latest receipt of
+/// every member. Subsequent items are `Update` containing newly received receipts.
+#[frb]
+#[derive(Debug, Clone)]
+pub enum ReadReceiptStreamItem {
+    /// Latest read receipt of each member at subscription time
+    InitialSnapshot { receipts: Vec<ReadReceipt> },
+    /// A member published a new read receipt
+    Update { receipt: ReadReceipt },
+}
+
+/// Subscribe to the read receipts published by members of a group.
+///
+/// Receipts are only published by members who enabled them in their account
+/// settings, and only into small groups and DMs.
+#[frb]
+pub async fn subscribe_to_read_receipts(
+    group_id: String,
+    sink: StreamSink<ReadReceiptStreamItem>,
+) -> Result<(), ApiError> {
+    let whitenoise = Whitenoise::get_instance()?;
+    let group_id = group_id_from_string(&group_id)?;
+
+    let subscription = whitenoise.subscribe_to_read_receipts(&group_id).await?;
+
+    // Emit initial snapshot first
+    let initial_receipts: Vec<ReadReceipt> = subscription
+        .initial_receipts
+        .into_iter()
+        .map(|r| r.into())
+        .collect();
+
+    if sink
+        .add(ReadReceiptStreamItem::InitialSnapshot {
+            receipts: initial_receipts,
+        })
+        .is_err()
+    {
+        return Ok(()); // Sink closed, exit gracefully
+    }
+
+    // Stream real-time updates
+    let mut rx = subscription.updates;
+    loop {
+        match rx.recv().await {
+            Ok(receipt) => {
+                let item = ReadReceiptStreamItem::Update {
+                    receipt: receipt.into(),
+                };
+                if sink.add(item).is_err() {
+                    break; // Sink closed
+                }
+            }
+            Err(tokio::sync::broadcast::error::RecvError::Lagged(_)) => {
+                // Slow consumer missed some receipts - safe to continue since
+                // each receipt supersedes the reader's earlier ones
+                continue;
+            }
+            Err(tokio::sync::broadcast::error::RecvError::Closed) => {
+                break; // Channel closed
+            }
+        }
+    }
+
+    Ok(())
+}
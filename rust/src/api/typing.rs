@@ -0,0 +1,208 @@
+use crate::api::error::ApiError;
+use crate::api::utils::group_id_from_string;
+use crate::frb_generated::StreamSink;
+use flutter_rust_bridge::frb;
+use nostr_sdk::PublicKey;
+use std::collections::HashMap;
+use std::sync::{LazyLock, Mutex};
+use std::time::{Duration, Instant};
+use whitenoise::Whitenoise;
+
+/// How long a member is shown as typing after their last typing indicator
+const TYPING_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Minimum time between two typing indicators sent by an account to a group.
+/// Shorter than `TYPING_TIMEOUT` so the indicator stays visible while typing.
+const TYPING_SEND_INTERVAL: Duration = Duration::from_secs(3);
+
+/// When each (account, group) pair last sent a typing indicator
+static LAST_TYPING_SENT: LazyLock<Mutex<HashMap<(String, String), Instant>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+/// Records an outgoing typing indicator unless one was sent for the same key
+/// within `TYPING_SEND_INTERVAL`. Returns whether the indicator should be sent.
+fn claim_typing_send<K: Eq + std::hash::Hash>(
+    last_sent: &mut HashMap<K, Instant>,
+    key: K,
+    now: Instant,
+) -> bool {
+    if last_sent
+        .get(&key)
+        .is_some_and(|sent_at| now.duration_since(*sent_at) < TYPING_SEND_INTERVAL)
+    {
+        return false;
+    }
+    last_sent.insert(key, now);
+    true
+}
+
+/// The members currently typing in a group.
+#[frb(non_opaque)]
+#[derive(Debug, Clone)]
+pub struct TypingUpdate {
+    pub mls_group_id: String,
+    /// Public keys (hex) of members currently typing, sorted
+    pub typing_pubkeys: Vec<String>,
+}
+
+/// Tracks which members are typing and when their indicator expires.
+#[derive(Debug, Default)]
+struct TypingState {
+    expires_at: HashMap<String, Instant>,
+}
+
+impl TypingState {
+    /// Records a typing indicator. Returns whether the set of typing members changed.
+    fn record(&mut self, pubkey: String, now: Instant) -> bool {
+        self.expires_at
+            .insert(pubkey, now + TYPING_TIMEOUT)
+            .is_none_or(|previous| previous <= now)
+    }
+
+    /// Drops expired indicators. Returns whether the set of typing members changed.
+    fn expire(&mut self, now: Instant) -> bool {
+        let before = self.expires_at.len();
+        self.expires_at.retain(|_, expires_at| *expires_at > now);
+        self.expires_at.len() != before
+    }
+
+    fn next_expiry(&self) -> Option<Instant> {
+        self.expires_at.values().min().copied()
+    }
+
+    fn typing_pubkeys(&self) -> Vec<String> {
+        let mut pubkeys: Vec<String> = self.expires_at.keys().cloned().collect();
+        pubkeys.sort();
+        pubkeys
+    }
+}
+
+/// Tells the other members of a group that the account is typing.
+///
+/// The indicator is sent as a short-lived group message that is never stored
+/// in the aggregated message history. It is safe to call on every keystroke:
+/// calls within a few seconds of the last indicator sent to the group are
+/// dropped. Receivers drop the indicator after a few seconds.
+#[frb]
+pub async fn send_typing_indicator(
+    account_pubkey: String,
+    group_id: String,
+) -> Result<(), ApiError> {
+    let whitenoise = Whitenoise::get_instance()?;
+    let pubkey = PublicKey::parse(&account_pubkey)?;
+    let account = whitenoise.find_account_by_pubkey(&pubkey).await?;
+    let mls_group_id = group_id_from_string(&group_id)?;
+
+    let key = (account_pubkey, group_id);
+    if !claim_typing_send(
+        &mut LAST_TYPING_SENT.lock().unwrap(),
+        key.clone(),
+        Instant::now(),
+    ) {
+        return Ok(());
+    }
+
+    let result = whitenoise
+        .send_typing_indicator(&account, &mls_group_id)
+        .await;
+    if result.is_err() {
+        // Let the next call retry right away
+        LAST_TYPING_SENT.lock().unwrap().remove(&key);
+    }
+    result.map_err(ApiError::from)
+}
+
+/// Subscribe to typing indicators of a group.
+///
+/// Emits a `TypingUpdate` with the full list of typing members whenever a
+/// member starts typing or their indicator expires. Indicators sent by the
+/// local account are included; filter them out on the Dart side if needed.
+#[frb]
+pub async fn subscribe_to_typing(
+    group_id: String,
+    sink: StreamSink<TypingUpdate>,
+) -> Result<(), ApiError> {
+    let whitenoise = Whitenoise::get_instance()?;
+    let mls_group_id = group_id_from_string(&group_id)?;
+    let mut rx = whitenoise.subscribe_to_typing(&mls_group_id).await?;
+    let mut state = TypingState::default();
+
+    loop {
+        let next_expiry = state.next_expiry();
+        let changed = tokio::select! {
+            result = rx.recv() => match result {
+                Ok(event) => state.record(event.author.to_hex(), Instant::now()),
+                // Missed indicators are refreshed by the next one the sender emits
+                Err(tokio::sync::broadcast::error::RecvError::Lagged(_)) => continue,
+                Err(tokio::sync::broadcast::error::RecvError::Closed) => break,
+            },
+            _ = tokio::time::sleep_until(
+                tokio::time::Instant::from_std(next_expiry.unwrap_or_else(Instant::now))
+            ), if next_expiry.is_some() => state.expire(Instant::now()),
+        };
+
+        if changed {
+            let update = TypingUpdate {
+                mls_group_id: group_id.clone(),
+                typing_pubkeys: state.typing_pubkeys(),
+            };
+            if sink.add(update).is_err() {
+                break; // Sink closed
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn typing_state_record_reports_new_members_only() {
+        let now = Instant::now();
+        let mut state = TypingState::default();
+        assert!(state.record("alice".to_string(), now));
+        assert!(!state.record("alice".to_string(), now + Duration::from_secs(1)));
+        assert!(state.record("bob".to_string(), now));
+        assert_eq!(state.typing_pubkeys(), vec!["alice", "bob"]);
+    }
+
+    #[test]
+    fn typing_state_expires_after_timeout() {
+        let now = Instant::now();
+        let mut state = TypingState::default();
+        state.record("alice".to_string(), now);
+        assert!(!state.expire(now + Duration::from_secs(1)));
+        assert!(state.expire(now + TYPING_TIMEOUT));
+        assert!(state.typing_pubkeys().is_empty());
+        assert_eq!(state.next_expiry(), None);
+    }
+
+    #[test]
+    fn claim_typing_send_debounces_per_key() {
+        let now = Instant::now();
+        let mut last_sent = HashMap::new();
+        assert!(claim_typing_send(&mut last_sent, "aa", now));
+        assert!(!claim_typing_send(
+            &mut last_sent,
+            "aa",
+            now + Duration::from_secs(1)
+        ));
+        assert!(claim_typing_send(&mut last_sent, "bb", now));
+        assert!(claim_typing_send(
+            &mut last_sent,
+            "aa",
+            now + TYPING_SEND_INTERVAL
+        ));
+    }
+
+    #[test]
+    fn typing_state_record_after_expiry_is_a_change() {
+        let now = Instant::now();
+        let mut state = TypingState::default();
+        state.record("alice".to_string(), now);
+        assert!(state.record("alice".to_string(), now + TYPING_TIMEOUT));
+    }
+}
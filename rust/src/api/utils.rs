@@ -5,6 +5,7 @@
 
 use crate::api::error::ApiError;
 use crate::api::{Language, ThemeMode};
+use chrono::{DateTime, TimeZone, Utc};
 use flutter_rust_bridge::frb;
 use mdk_core::prelude::GroupId;
 use nostr_sdk::prelude::*;
@@ -59,6 +60,15 @@ pub fn group_id_from_string(group_id: &str) -> Result<GroupId, ApiError> {
     Ok(GroupId::from_slice(&bytes))
 }
 
+/// Converts a nostr timestamp to a UTC date time, falling back to the Unix
+/// epoch for out-of-range values.
+pub(crate) fn timestamp_to_datetime(timestamp: Timestamp) -> DateTime<Utc> {
+    let ts = i64::try_from(timestamp.as_secs()).unwrap_or(0);
+    Utc.timestamp_opt(ts, 0)
+        .single()
+        .unwrap_or_else(|| Utc.timestamp_opt(0, 0).single().unwrap())
+}
+
 #[frb(sync)]
 pub fn theme_mode_light() -> ThemeMode {
     ThemeMode::Light
@@ -16,6 +16,7 @@ ChatMessage _createTestMessage({String id = 'msg-1', String content = 'Test mess
     reactions: const ReactionSummary(byEmoji: [], userReactions: []),
     mediaAttachments: const [],
     kind: 9,
+    editHistory: const [],
   );
 }
 
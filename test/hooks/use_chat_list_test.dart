@@ -13,6 +13,8 @@ ChatSummary _chatSummary(String id, DateTime createdAt) => ChatSummary(
   createdAt: createdAt,
   pendingConfirmation: false,
   unreadCount: BigInt.zero,
+  notificationLevel: NotificationLevel.all,
+  archived: false,
 );
 
 class _MockApi implements RustLibApi {
@@ -33,6 +35,7 @@ class _MockApi implements RustLibApi {
   @override
   Stream<ChatListStreamItem> crateApiChatListSubscribeToChatList({
     required String accountPubkey,
+    required ChatListFilter filter,
   }) {
     controller?.close();
     controller = StreamController<ChatListStreamItem>.broadcast();
@@ -174,6 +177,8 @@ void main() {
           createdAt: DateTime(2024),
           pendingConfirmation: false,
           unreadCount: BigInt.zero,
+          notificationLevel: NotificationLevel.all,
+          archived: false,
         );
         _api.emitUpdate(ChatListUpdateTrigger.lastMessageDeleted, updatedChat);
         await tester.pumpAndSettle();
@@ -195,6 +200,8 @@ void main() {
           createdAt: DateTime(2024),
           pendingConfirmation: false,
           unreadCount: BigInt.zero,
+          notificationLevel: NotificationLevel.all,
+          archived: false,
         );
         _api.emitUpdate(ChatListUpdateTrigger.lastMessageDeleted, updatedChat);
         await tester.pumpAndSettle();
@@ -4,6 +4,7 @@ import 'package:flutter_test/flutter_test.dart';
 import 'package:whitenoise/hooks/use_chat_messages.dart';
 import 'package:whitenoise/src/rust/api/messages.dart';
 import 'package:whitenoise/src/rust/api/metadata.dart';
+import 'package:whitenoise/src/rust/api/outbox.dart';
 import 'package:whitenoise/src/rust/frb_generated.dart';
 
 import '../mocks/mock_wn_api.dart';
@@ -16,6 +17,7 @@ ChatMessage _message(
   String pubkey = testPubkeyA,
   bool isDeleted = false,
   ReactionSummary reactions = const ReactionSummary(byEmoji: [], userReactions: []),
+  SystemEvent? systemEvent,
 }) => ChatMessage(
   id: id,
   pubkey: pubkey,
@@ -28,6 +30,21 @@ ChatMessage _message(
   reactions: reactions,
   mediaAttachments: const [],
   kind: 9,
+  editHistory: const [],
+  systemEvent: systemEvent,
+);
+
+OutboxMessage _outboxMessage(String localId, OutboxStatus status) => OutboxMessage(
+  localId: localId,
+  accountPubkey: testPubkeyA,
+  mlsGroupId: 'group1',
+  content: 'queued',
+  kind: 9,
+  tags: const [],
+  status: status,
+  attempts: 0,
+  createdAt: DateTime(2024),
+  updatedAt: DateTime(2024),
 );
 
 const _emptyMetadata = FlutterMetadata(custom: {});
@@ -36,9 +53,27 @@ enum _MetadataMode { normal, emptyThenSuccess }
 
 class _MockApi extends MockWnApi {
   StreamController<MessageStreamItem>? controller;
+  int? requestedSnapshotLimit;
+  final olderPageRequests = <({String subscriptionId, int limit})>[];
 
-  void emitInitialSnapshot(List<ChatMessage> messages) {
-    controller?.add(MessageStreamItem.initialSnapshot(messages: messages));
+  void emitInitialSnapshot(List<ChatMessage> messages, {bool hasMoreBefore = false}) {
+    controller?.add(
+      MessageStreamItem.initialSnapshot(
+        subscriptionId: 'sub1',
+        messages: messages,
+        hasMoreBefore: hasMoreBefore,
+      ),
+    );
+  }
+
+  void emitOutboxUpdate(OutboxMessage message) {
+    controller?.add(MessageStreamItem.outboxUpdate(message: message));
+  }
+
+  void emitOlderPage(List<ChatMessage> messages, {bool hasMoreBefore = false}) {
+    controller?.add(
+      MessageStreamItem.olderPage(messages: messages, hasMoreBefore: hasMoreBefore),
+    );
   }
 
   void emitNewMessage(ChatMessage message) {
@@ -76,12 +111,22 @@ class _MockApi extends MockWnApi {
   @override
   Stream<MessageStreamItem> crateApiMessagesSubscribeToGroupMessages({
     required String groupId,
+    int? snapshotLimit,
   }) {
+    requestedSnapshotLimit = snapshotLimit;
     controller?.close();
     controller = StreamController<MessageStreamItem>.broadcast();
     return controller!.stream;
   }
 
+  @override
+  Future<void> crateApiMessagesLoadOlderGroupMessages({
+    required String subscriptionId,
+    required int limit,
+  }) async {
+    olderPageRequests.add((subscriptionId: subscriptionId, limit: limit));
+  }
+
   FlutterMetadata? userMetadataResponse;
   _MetadataMode metadataMode = _MetadataMode.normal;
   final metadataCalls = <({String pubkey, bool blocking})>[];
@@ -122,6 +167,8 @@ void main() {
   setUp(() {
     _api.controller?.close();
     _api.controller = null;
+    _api.requestedSnapshotLimit = null;
+    _api.olderPageRequests.clear();
     _api.userMetadataResponse = null;
     _api.metadataMode = _MetadataMode.normal;
     _api.metadataCalls.clear();
@@ -191,6 +238,122 @@ void main() {
       expect(result.getMessage(0).id, 'm2');
     });
 
+    group('older messages', () {
+      testWidgets('requests a limited initial snapshot', (tester) async {
+        await _pump(tester, 'group1');
+
+        expect(_api.requestedSnapshotLimit, 50);
+      });
+
+      testWidgets('hasMoreBefore follows the initial snapshot', (tester) async {
+        final getResult = await _pump(tester, 'group1');
+
+        _api.emitInitialSnapshot([_message('m2', DateTime(2024, 1, 2))], hasMoreBefore: true);
+        await tester.pumpAndSettle();
+
+        expect(getResult().hasMoreBefore, isTrue);
+      });
+
+      testWidgets('loadOlderMessages requests the next page on the subscription', (tester) async {
+        final getResult = await _pump(tester, 'group1');
+
+        _api.emitInitialSnapshot([_message('m2', DateTime(2024, 1, 2))], hasMoreBefore: true);
+        await tester.pumpAndSettle();
+        await getResult().loadOlderMessages();
+
+        expect(_api.olderPageRequests, [(subscriptionId: 'sub1', limit: 50)]);
+      });
+
+      testWidgets('loadOlderMessages does nothing without older messages', (tester) async {
+        final getResult = await _pump(tester, 'group1');
+
+        _api.emitInitialSnapshot([_message('m2', DateTime(2024, 1, 2))]);
+        await tester.pumpAndSettle();
+        await getResult().loadOlderMessages();
+
+        expect(_api.olderPageRequests, isEmpty);
+      });
+
+      testWidgets('loadOlderMessages waits for the pending page', (tester) async {
+        final getResult = await _pump(tester, 'group1');
+
+        _api.emitInitialSnapshot([_message('m2', DateTime(2024, 1, 2))], hasMoreBefore: true);
+        await tester.pumpAndSettle();
+        await getResult().loadOlderMessages();
+        await getResult().loadOlderMessages();
+
+        expect(_api.olderPageRequests.length, 1);
+      });
+
+      testWidgets('older page is added before existing messages', (tester) async {
+        final getResult = await _pump(tester, 'group1');
+
+        _api.emitInitialSnapshot([_message('m3', DateTime(2024, 1, 3))], hasMoreBefore: true);
+        await tester.pumpAndSettle();
+        _api.emitOlderPage([
+          _message('m1', DateTime(2024)),
+          _message('m2', DateTime(2024, 1, 2)),
+        ]);
+        await tester.pumpAndSettle();
+
+        final result = getResult();
+        expect(result.messageCount, 3);
+        expect(result.getMessage(0).id, 'm3');
+        expect(result.getMessage(2).id, 'm1');
+        expect(result.getReversedMessageIndex('m1'), 2);
+        expect(result.latestMessageId, 'm3');
+        expect(result.hasMoreBefore, isFalse);
+      });
+    });
+
+    group('outboxMessages', () {
+      testWidgets('is empty before any outbox update', (tester) async {
+        final getResult = await _pump(tester, 'group1');
+
+        _api.emitInitialSnapshot([]);
+        await tester.pumpAndSettle();
+
+        expect(getResult().outboxMessages, isEmpty);
+      });
+
+      testWidgets('tracks queued and failed messages', (tester) async {
+        final getResult = await _pump(tester, 'group1');
+
+        _api.emitInitialSnapshot([]);
+        _api.emitOutboxUpdate(_outboxMessage('l1', const OutboxStatus.queued()));
+        _api.emitOutboxUpdate(_outboxMessage('l2', const OutboxStatus.failed(reason: 'offline')));
+        await tester.pumpAndSettle();
+
+        final outbox = getResult().outboxMessages;
+        expect(outbox.map((m) => m.localId), ['l1', 'l2']);
+        expect(outbox.last.status, const OutboxStatus.failed(reason: 'offline'));
+      });
+
+      testWidgets('updates the status of a tracked message', (tester) async {
+        final getResult = await _pump(tester, 'group1');
+
+        _api.emitInitialSnapshot([]);
+        _api.emitOutboxUpdate(_outboxMessage('l1', const OutboxStatus.queued()));
+        _api.emitOutboxUpdate(_outboxMessage('l1', const OutboxStatus.publishing()));
+        await tester.pumpAndSettle();
+
+        final outbox = getResult().outboxMessages;
+        expect(outbox.length, 1);
+        expect(outbox.single.status, const OutboxStatus.publishing());
+      });
+
+      testWidgets('drops messages once published', (tester) async {
+        final getResult = await _pump(tester, 'group1');
+
+        _api.emitInitialSnapshot([]);
+        _api.emitOutboxUpdate(_outboxMessage('l1', const OutboxStatus.queued()));
+        _api.emitOutboxUpdate(_outboxMessage('l1', OutboxStatus.published(relayCount: BigInt.one)));
+        await tester.pumpAndSettle();
+
+        expect(getResult().outboxMessages, isEmpty);
+      });
+    });
+
     group('getReversedMessageIndex', () {
       testWidgets('returns correct index for messages', (tester) async {
         final getResult = await _pump(tester, 'group1');
@@ -272,6 +435,25 @@ void main() {
           expect(getResult().latestMessageId, 'm2');
         });
       });
+
+      group('when latest entry is a system event', () {
+        testWidgets('skips it', (tester) async {
+          final getResult = await _pump(tester, 'group1');
+
+          _api.emitInitialSnapshot([
+            _message('m1', DateTime(2024)),
+            _message(
+              's1',
+              DateTime(2024, 1, 2),
+              content: '',
+              systemEvent: const SystemEvent.memberLeft(actor: testPubkeyB),
+            ),
+          ]);
+          await tester.pumpAndSettle();
+
+          expect(getResult().latestMessageId, 'm1');
+        });
+      });
     });
 
     group('messageDeleted', () {
@@ -354,6 +536,31 @@ void main() {
           expect(getResult().latestMessagePubkey, testPubkeyC);
         });
       });
+
+      group('when latest entry is a system event', () {
+        testWidgets('skips it', (tester) async {
+          final getResult = await _pump(tester, 'group1');
+
+          _api.emitInitialSnapshot([_message('m1', DateTime(2024), pubkey: testPubkeyB)]);
+          await tester.pumpAndSettle();
+
+          _api.emitNewMessage(
+            _message(
+              's1',
+              DateTime(2024, 1, 2),
+              pubkey: testPubkeyC,
+              content: '',
+              systemEvent: const SystemEvent.membersAdded(
+                actor: testPubkeyC,
+                members: [testPubkeyA],
+              ),
+            ),
+          );
+          await tester.pumpAndSettle();
+
+          expect(getResult().latestMessagePubkey, testPubkeyB);
+        });
+      });
     });
 
     group('reactionAdded', () {
@@ -12,6 +12,7 @@ class _TestWidget extends HookWidget {
     required this.latestMessageId,
     this.isLatestMessageOwn = false,
     this.itemCount = 50,
+    this.onNearTop,
   });
 
   final ScrollController scrollController;
@@ -19,6 +20,7 @@ class _TestWidget extends HookWidget {
   final String? latestMessageId;
   final bool isLatestMessageOwn;
   final int itemCount;
+  final VoidCallback? onNearTop;
 
   @override
   Widget build(BuildContext context) {
@@ -27,6 +29,7 @@ class _TestWidget extends HookWidget {
       focusNode: focusNode,
       latestMessageId: latestMessageId,
       isLatestMessageOwn: isLatestMessageOwn,
+      onNearTop: onNearTop,
     );
 
     return MaterialApp(
@@ -69,6 +72,7 @@ void main() {
     String? latestMessageId,
     bool isLatestMessageOwn = false,
     int itemCount = 50,
+    VoidCallback? onNearTop,
   }) async {
     await tester.pumpWidget(
       _TestWidget(
@@ -77,6 +81,7 @@ void main() {
         latestMessageId: latestMessageId,
         isLatestMessageOwn: isLatestMessageOwn,
         itemCount: itemCount,
+        onNearTop: onNearTop,
       ),
     );
     await tester.pumpAndSettle();
@@ -271,5 +276,27 @@ void main() {
         expect(scrollController.position.pixels, 0);
       });
     });
+
+    group('onNearTop', () {
+      testWidgets('is called when scrolled near the oldest message', (tester) async {
+        var calls = 0;
+        await pumpWidget(tester, onNearTop: () => calls++);
+
+        scrollController.jumpTo(scrollController.position.maxScrollExtent);
+        await tester.pumpAndSettle();
+
+        expect(calls, greaterThan(0));
+      });
+
+      testWidgets('is not called when scrolling away from the top', (tester) async {
+        var calls = 0;
+        await pumpWidget(tester, onNearTop: () => calls++);
+
+        scrollController.jumpTo(100);
+        await tester.pumpAndSettle();
+
+        expect(calls, 0);
+      });
+    });
   });
 }
@@ -143,8 +143,15 @@ class MockWnApi implements RustLibApi {
   @override
   Stream<MessageStreamItem> crateApiMessagesSubscribeToGroupMessages({
     required String groupId,
+    int? snapshotLimit,
   }) {
-    return Stream.value(const MessageStreamItem.initialSnapshot(messages: []));
+    return Stream.value(
+      const MessageStreamItem.initialSnapshot(
+        subscriptionId: '1',
+        messages: [],
+        hasMoreBefore: false,
+      ),
+    );
   }
 
   @override
@@ -162,6 +169,7 @@ class MockWnApi implements RustLibApi {
   @override
   Stream<ChatListStreamItem> crateApiChatListSubscribeToChatList({
     required String accountPubkey,
+    required ChatListFilter filter,
   }) {
     return Stream.value(const ChatListStreamItem.initialSnapshot(items: []));
   }
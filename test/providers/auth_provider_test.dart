@@ -105,7 +105,10 @@ class _MockRustLibApi implements RustLibApi {
   }
 
   @override
-  Future<Account> crateApiAccountsLogin({required String nsecOrHexPrivkey}) async {
+  Future<Account> crateApiAccountsLogin({
+    required String nsecOrHexPrivkey,
+    String? password,
+  }) async {
     existingAccounts.add(testPubkeyB);
     return Account(
       pubkey: testPubkeyB,
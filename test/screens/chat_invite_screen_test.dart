@@ -10,6 +10,7 @@ import 'package:whitenoise/screens/chat_list_screen.dart';
 import 'package:whitenoise/screens/chat_screen.dart';
 import 'package:whitenoise/screens/wip_screen.dart';
 import 'package:whitenoise/src/rust/api/account_groups.dart';
+import 'package:whitenoise/src/rust/api/chat_list.dart' show NotificationLevel;
 import 'package:whitenoise/src/rust/api/groups.dart';
 import 'package:whitenoise/src/rust/api/messages.dart';
 import 'package:whitenoise/src/rust/api/metadata.dart';
@@ -47,6 +48,7 @@ ChatMessage _message(
   reactions: const ReactionSummary(byEmoji: [], userReactions: []),
   mediaAttachments: const [],
   kind: 9,
+  editHistory: const [],
 );
 
 AccountGroup _accountGroup() => AccountGroup(
@@ -54,6 +56,7 @@ AccountGroup _accountGroup() => AccountGroup(
   mlsGroupId: _testGroupId,
   createdAt: PlatformInt64Util.from(0),
   updatedAt: PlatformInt64Util.from(0),
+  notificationLevel: NotificationLevel.all,
 );
 
 class _MockApi extends MockWnApi {
@@ -100,12 +103,17 @@ class _MockApi extends MockWnApi {
   @override
   Stream<MessageStreamItem> crateApiMessagesSubscribeToGroupMessages({
     required String groupId,
+    int? snapshotLimit,
   }) {
     controller?.close();
     controller = StreamController<MessageStreamItem>.broadcast();
     Future.microtask(() {
       controller?.add(
-        MessageStreamItem.initialSnapshot(messages: initialMessages),
+        MessageStreamItem.initialSnapshot(
+          subscriptionId: '1',
+          messages: initialMessages,
+          hasMoreBefore: false,
+        ),
       );
     });
     return controller!.stream;
@@ -32,6 +32,8 @@ ChatSummary _chatSummary({
   createdAt: DateTime(2024),
   pendingConfirmation: pendingConfirmation,
   unreadCount: BigInt.zero,
+  notificationLevel: NotificationLevel.all,
+  archived: false,
 );
 
 class _MockApi extends MockWnApi {
@@ -48,6 +50,7 @@ class _MockApi extends MockWnApi {
   @override
   Stream<ChatListStreamItem> crateApiChatListSubscribeToChatList({
     required String accountPubkey,
+    required ChatListFilter filter,
   }) {
     controller?.close();
     controller = StreamController<ChatListStreamItem>.broadcast();
@@ -15,6 +15,7 @@ import 'package:whitenoise/widgets/wn_avatar.dart';
 import 'package:whitenoise/widgets/wn_message_bubble.dart';
 import 'package:whitenoise/widgets/wn_reply_preview.dart';
 import 'package:whitenoise/widgets/wn_system_notice.dart';
+import 'package:whitenoise/widgets/wn_timeline_notice.dart';
 
 import '../mocks/mock_wn_api.dart';
 import '../test_helpers.dart';
@@ -22,17 +23,6 @@ import '../test_helpers.dart';
 const _testPubkey = testPubkeyA;
 const _testGroupId = testGroupId;
 
-class _MockTag implements Tag {
-  final List<String> vec;
-  _MockTag(this.vec);
-
-  @override
-  void dispose() {}
-
-  @override
-  bool get isDisposed => false;
-}
-
 ChatMessage _message(
   String id,
   DateTime createdAt, {
@@ -41,6 +31,7 @@ ChatMessage _message(
   bool isReply = false,
   String? replyToId,
   ReactionSummary reactions = const ReactionSummary(byEmoji: [], userReactions: []),
+  SystemEvent? systemEvent,
 }) => ChatMessage(
   id: id,
   pubkey: pubkey,
@@ -54,6 +45,8 @@ ChatMessage _message(
   reactions: reactions,
   mediaAttachments: const [],
   kind: 9,
+  editHistory: const [],
+  systemEvent: systemEvent,
 );
 
 class _MockApi extends MockWnApi {
@@ -61,12 +54,14 @@ class _MockApi extends MockWnApi {
   List<ChatMessage> initialMessages = [];
   String groupName = 'Test Group';
   final List<String> sentMessages = [];
-  final List<({String groupId, int kind, List<Tag>? tags})> deletionCalls = [];
-  final List<({String groupId, String message, int kind, List<Tag>? tags})> reactionCalls = [];
-  final List<List<List<String>>> sentTextMessageTagVecs = [];
+  final List<({String messageId, String message})> replyCalls = [];
+  final List<({String groupId, String messageId})> deletionCalls = [];
+  final List<({String groupId, String messageId, String emoji})> reactionCalls = [];
+  final List<({String groupId, String messageId, String emoji})> reactionRemovalCalls = [];
   Exception? sendError;
   Exception? deleteError;
   Exception? reactionError;
+  Exception? removeReactionError;
   int _sendCallCount = 0;
   bool isDm = false;
   List<String> groupMembers = [];
@@ -78,22 +73,19 @@ class _MockApi extends MockWnApi {
     initialMessages = [];
     groupName = 'Test Group';
     sentMessages.clear();
+    replyCalls.clear();
     deletionCalls.clear();
     reactionCalls.clear();
-    sentTextMessageTagVecs.clear();
+    reactionRemovalCalls.clear();
     sendError = null;
     deleteError = null;
     reactionError = null;
+    removeReactionError = null;
     _sendCallCount = 0;
     isDm = false;
     groupMembers = [];
   }
 
-  @override
-  Future<Tag> crateApiUtilsTagFromVec({required List<String> vec}) async {
-    return _MockTag(vec);
-  }
-
   void emitMessage(ChatMessage message) {
     controller?.add(
       MessageStreamItem.update(
@@ -110,27 +102,67 @@ class _MockApi extends MockWnApi {
     required int kind,
     List<Tag>? tags,
   }) async {
-    _sendCallCount++;
+    if (sendError != null) throw sendError!;
+    sentMessages.add(message);
+    return _sent(pubkey: pubkey, kind: kind, content: message);
+  }
 
-    if (kind == 5) {
-      if (deleteError != null) throw deleteError!;
-      deletionCalls.add((groupId: groupId, kind: kind, tags: tags));
-    } else if (kind == 7) {
-      if (reactionError != null) throw reactionError!;
-      reactionCalls.add((groupId: groupId, message: message, kind: kind, tags: tags));
-    } else {
-      if (sendError != null) throw sendError!;
-      sentMessages.add(message);
-      if (tags != null) {
-        sentTextMessageTagVecs.add(tags.cast<_MockTag>().map((t) => t.vec).toList());
-      }
-    }
+  @override
+  Future<MessageWithTokens> crateApiMessagesSendReply({
+    required String pubkey,
+    required String groupId,
+    required String messageId,
+    required String message,
+  }) async {
+    if (sendError != null) throw sendError!;
+    sentMessages.add(message);
+    replyCalls.add((messageId: messageId, message: message));
+    return _sent(pubkey: pubkey, kind: 9, content: message);
+  }
+
+  @override
+  Future<MessageWithTokens> crateApiMessagesSendReaction({
+    required String pubkey,
+    required String groupId,
+    required String messageId,
+    required String emoji,
+  }) async {
+    if (reactionError != null) throw reactionError!;
+    reactionCalls.add((groupId: groupId, messageId: messageId, emoji: emoji));
+    return _sent(pubkey: pubkey, kind: 7, content: emoji);
+  }
+
+  @override
+  Future<MessageWithTokens> crateApiMessagesRemoveReaction({
+    required String pubkey,
+    required String groupId,
+    required String messageId,
+    required String emoji,
+  }) async {
+    if (removeReactionError != null) throw removeReactionError!;
+    reactionRemovalCalls.add((groupId: groupId, messageId: messageId, emoji: emoji));
+    return _sent(pubkey: pubkey, kind: 5, content: '');
+  }
+
+  @override
+  Future<MessageWithTokens> crateApiMessagesDeleteMessage({
+    required String pubkey,
+    required String groupId,
+    required String messageId,
+  }) async {
+    if (deleteError != null) throw deleteError!;
+    deletionCalls.add((groupId: groupId, messageId: messageId));
+    return _sent(pubkey: pubkey, kind: 5, content: '');
+  }
+
+  MessageWithTokens _sent({required String pubkey, required int kind, required String content}) {
+    _sendCallCount++;
     return MessageWithTokens(
       id: 'mock_$_sendCallCount',
       pubkey: pubkey,
       kind: kind,
       createdAt: DateTime.now(),
-      content: message,
+      content: content,
       tokens: const [],
     );
   }
@@ -138,12 +170,17 @@ class _MockApi extends MockWnApi {
   @override
   Stream<MessageStreamItem> crateApiMessagesSubscribeToGroupMessages({
     required String groupId,
+    int? snapshotLimit,
   }) {
     controller?.close();
     controller = StreamController<MessageStreamItem>.broadcast();
     Future.microtask(() {
       controller?.add(
-        MessageStreamItem.initialSnapshot(messages: initialMessages),
+        MessageStreamItem.initialSnapshot(
+          subscriptionId: '1',
+          messages: initialMessages,
+          hasMoreBefore: false,
+        ),
       );
     });
     return controller!.stream;
@@ -311,6 +348,21 @@ void main() {
 
         expect(find.text('Message m2'), findsNothing);
       });
+
+      testWidgets('displays system entries as timeline notices', (tester) async {
+        _api.initialMessages = [
+          _message('m1', DateTime(2024, 1, 2)),
+          _message(
+            's1',
+            DateTime(2024, 1, 3),
+            systemEvent: const SystemEvent.memberLeft(actor: testPubkeyB),
+          ),
+        ];
+        await pumpChatScreen(tester);
+
+        expect(find.byType(WnMessageBubble), findsOneWidget);
+        expect(find.byType(WnTimelineNotice), findsOneWidget);
+      });
     });
 
     group('navigation', () {
@@ -521,6 +573,22 @@ void main() {
         expect(find.byType(MessageActionsScreen), findsOneWidget);
       });
 
+      testWidgets('does not open for system entries', (tester) async {
+        _api.initialMessages = [
+          _message(
+            's1',
+            DateTime(2024),
+            systemEvent: const SystemEvent.memberLeft(actor: testPubkeyB),
+          ),
+        ];
+        await pumpChatScreen(tester);
+
+        await tester.longPress(find.byType(WnTimelineNotice));
+        await tester.pumpAndSettle();
+
+        expect(find.byType(MessageActionsScreen), findsNothing);
+      });
+
       testWidgets('shows Delete button for own message', (tester) async {
         _api.initialMessages = [
           _message('m1', DateTime(2024), pubkey: _testPubkey),
@@ -669,8 +737,7 @@ void main() {
           await tester.tap(find.text('Delete'));
           await tester.pumpAndSettle();
 
-          final tags = _api.deletionCalls.first.tags!.cast<_MockTag>();
-          expect(tags[0].vec, ['e', 'msg_to_delete']);
+          expect(_api.deletionCalls.first.messageId, 'msg_to_delete');
         });
 
         testWidgets('shows system notice when deletion fails', (tester) async {
@@ -718,7 +785,7 @@ void main() {
           await tester.tap(find.text('🚀'));
           await tester.pumpAndSettle();
 
-          expect(_api.reactionCalls.first.message, '🚀');
+          expect(_api.reactionCalls.first.emoji, '🚀');
         });
 
         testWidgets('sends reaction to correct group', (tester) async {
@@ -735,7 +802,7 @@ void main() {
           expect(_api.reactionCalls.first.groupId, _testGroupId);
         });
 
-        testWidgets('includes message reference in reaction tags', (tester) async {
+        testWidgets('reacts to expected message id', (tester) async {
           _api.initialMessages = [
             _message('msg_to_react', DateTime(2024)),
           ];
@@ -746,8 +813,7 @@ void main() {
           await tester.tap(find.text('❤'));
           await tester.pumpAndSettle();
 
-          final tags = _api.reactionCalls.first.tags!.cast<_MockTag>();
-          expect(tags[0].vec, ['e', 'msg_to_react']);
+          expect(_api.reactionCalls.first.messageId, 'msg_to_react');
         });
 
         testWidgets('closes message actions after sending reaction', (tester) async {
@@ -798,7 +864,7 @@ void main() {
           ],
         );
 
-        testWidgets('calls delete API when tapping selected emoji', (tester) async {
+        testWidgets('calls remove reaction API when tapping selected emoji', (tester) async {
           _api.initialMessages = [
             _message('m1', DateTime(2024), reactions: ownReaction('❤', 'reaction_1')),
           ];
@@ -808,10 +874,10 @@ void main() {
           await tester.tap(find.byKey(const Key('reaction_❤')));
           await tester.pumpAndSettle();
 
-          expect(_api.deletionCalls.length, 1);
+          expect(_api.reactionRemovalCalls.length, 1);
         });
 
-        testWidgets('sends correct reaction ID in deletion tags', (tester) async {
+        testWidgets('removes selected emoji from expected message', (tester) async {
           _api.initialMessages = [
             _message('m1', DateTime(2024), reactions: ownReaction('❤', 'reaction_to_remove')),
           ];
@@ -821,8 +887,8 @@ void main() {
           await tester.tap(find.byKey(const Key('reaction_❤')));
           await tester.pumpAndSettle();
 
-          final tags = _api.deletionCalls.first.tags!.cast<_MockTag>();
-          expect(tags[0].vec, ['e', 'reaction_to_remove']);
+          expect(_api.reactionRemovalCalls.first.messageId, 'm1');
+          expect(_api.reactionRemovalCalls.first.emoji, '❤');
         });
 
         testWidgets('closes message actions after removing reaction', (tester) async {
@@ -839,7 +905,7 @@ void main() {
         });
 
         testWidgets('shows system notice when reaction removal fails', (tester) async {
-          _api.deleteError = Exception('Network error');
+          _api.removeReactionError = Exception('Network error');
           _api.initialMessages = [
             _message('m1', DateTime(2024), reactions: ownReaction('❤', 'reaction_1')),
           ];
@@ -849,7 +915,7 @@ void main() {
           await tester.tap(find.byKey(const Key('reaction_❤')));
           await tester.pumpAndSettle();
 
-          expect(_api.deletionCalls.length, 0);
+          expect(_api.reactionRemovalCalls.length, 0);
           expect(find.byType(WnSystemNotice), findsOneWidget);
           expect(find.text('Failed to remove reaction. Please try again.'), findsOneWidget);
           expect(find.byType(MessageActionsScreen), findsOneWidget);
@@ -901,9 +967,7 @@ void main() {
         await tester.pumpAndSettle();
 
         expect(_api.sentMessages.last, 'My reply');
-        expect(_api.sentTextMessageTagVecs, isNotEmpty);
-        final tagVecs = _api.sentTextMessageTagVecs.last;
-        expect(tagVecs.any((t) => t.isNotEmpty && t[0] == 'e'), isTrue);
+        expect(_api.replyCalls.last.messageId, 'm1');
       });
 
       testWidgets('cancel reply hides reply preview in input', (tester) async {
@@ -997,10 +1061,10 @@ void main() {
           await tester.tap(find.text('🔥'));
           await tester.pumpAndSettle();
 
-          expect(_api.reactionCalls.first.message, '🔥');
+          expect(_api.reactionCalls.first.emoji, '🔥');
         });
 
-        testWidgets('includes message reference in reaction tags', (tester) async {
+        testWidgets('reacts to the message of the tapped pill', (tester) async {
           _api.initialMessages = [
             _message('msg_with_reaction', DateTime(2024), reactions: reactionFromOther('👍')),
           ];
@@ -1009,13 +1073,12 @@ void main() {
           await tester.tap(find.text('👍'));
           await tester.pumpAndSettle();
 
-          final tags = _api.reactionCalls.first.tags!.cast<_MockTag>();
-          expect(tags[0].vec, ['e', 'msg_with_reaction']);
+          expect(_api.reactionCalls.first.messageId, 'msg_with_reaction');
         });
       });
 
       group('when user has a reaction to the message', () {
-        testWidgets('calls delete API when tapping own reaction', (tester) async {
+        testWidgets('calls remove reaction API when tapping own reaction', (tester) async {
           final ownReaction = ReactionSummary(
             byEmoji: [
               EmojiReaction(emoji: '👍', count: BigInt.one, users: const [_testPubkey]),
@@ -1035,9 +1098,8 @@ void main() {
           await tester.tap(find.text('👍'));
           await tester.pumpAndSettle();
 
-          expect(_api.deletionCalls.length, 1);
-          final tags = _api.deletionCalls.first.tags!.cast<_MockTag>();
-          expect(tags[0].vec, ['e', 'reaction_to_delete']);
+          expect(_api.reactionRemovalCalls.length, 1);
+          expect(_api.reactionRemovalCalls.first.emoji, '👍');
         });
       });
     });
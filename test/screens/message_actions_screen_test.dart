@@ -28,6 +28,7 @@ ChatMessage _createTestMessage({
     reactions: reactions ?? const ReactionSummary(byEmoji: [], userReactions: []),
     mediaAttachments: const [],
     kind: 9,
+    editHistory: const [],
   );
 }
 
@@ -740,7 +741,7 @@ void main() {
 
     testWidgets('calls onRemoveReaction when tapping already reacted emoji', (tester) async {
       const myPubkey = testPubkeyA;
-      String? removedReactionId;
+      String? removedEmoji;
       final message = _createTestMessage(
         pubkey: testPubkeyB,
         reactions: ReactionSummary(
@@ -766,8 +767,8 @@ void main() {
             message: message,
             pubkey: myPubkey,
             onAddReaction: (_) async {},
-            onRemoveReaction: (reactionId) async {
-              removedReactionId = reactionId;
+            onRemoveReaction: (emoji) async {
+              removedEmoji = emoji;
             },
           ),
           child: const Text('Show Menu'),
@@ -780,7 +781,7 @@ void main() {
       await tester.tap(find.byKey(const Key('reaction_❤')));
       await tester.pumpAndSettle();
 
-      expect(removedReactionId, 'reaction-to-remove');
+      expect(removedEmoji, '❤');
       expect(find.text('Message actions'), findsNothing);
     });
 
@@ -811,7 +812,7 @@ void main() {
             message: message,
             pubkey: myPubkey,
             onAddReaction: (_) async {},
-            onRemoveReaction: (reactionId) async {
+            onRemoveReaction: (_) async {
               throw Exception('Remove reaction failed');
             },
           ),
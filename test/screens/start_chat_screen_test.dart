@@ -49,13 +49,15 @@ class _MockApi extends MockWnApi {
   }
 
   @override
-  Future<Group> crateApiGroupsCreateGroup({
+  Future<CreateGroupResult> crateApiGroupsCreateGroup({
     required String creatorPubkey,
     required List<String> memberPubkeys,
     required List<String> adminPubkeys,
     required String groupName,
     required String groupDescription,
     required GroupType groupType,
+    List<String>? relays,
+    String? imagePath,
   }) async {
     createGroupCalls.add((
       creatorPubkey: creatorPubkey,
@@ -63,19 +65,28 @@ class _MockApi extends MockWnApi {
       groupType: groupType,
     ));
 
-    if (createGroupCompleter != null) return createGroupCompleter!.future;
+    if (createGroupCompleter != null) {
+      return CreateGroupResult(
+        group: await createGroupCompleter!.future,
+        skippedMembers: const [],
+      );
+    }
     if (createGroupError != null) throw createGroupError!;
 
-    return createdGroup ??
-        Group(
-          mlsGroupId: testGroupId,
-          nostrGroupId: testNostrGroupId,
-          name: '',
-          description: '',
-          adminPubkeys: const [],
-          epoch: BigInt.zero,
-          state: GroupState.active,
-        );
+    return CreateGroupResult(
+      group:
+          createdGroup ??
+          Group(
+            mlsGroupId: testGroupId,
+            nostrGroupId: testNostrGroupId,
+            name: '',
+            description: '',
+            adminPubkeys: const [],
+            epoch: BigInt.zero,
+            state: GroupState.active,
+          ),
+      skippedMembers: const [],
+    );
   }
 
   @override
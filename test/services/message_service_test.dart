@@ -8,24 +8,26 @@ import '../test_helpers.dart';
 
 const _testPubkey = testPubkeyA;
 
-class _MockTag implements Tag {
-  final List<String> vec;
-  _MockTag(this.vec);
-
-  @override
-  void dispose() {}
-
-  @override
-  bool get isDisposed => false;
-}
+typedef _TargetedCall = ({String pubkey, String groupId, String messageId, String content});
 
 class _MockApi extends MockWnApi {
   final List<({String pubkey, String groupId, String message, int kind, List<Tag>? tags})>
   sentMessages = [];
+  final List<_TargetedCall> replies = [];
+  final List<_TargetedCall> reactions = [];
+  final List<_TargetedCall> reactionRemovals = [];
+  final List<_TargetedCall> deletions = [];
+  int _callCount = 0;
 
-  @override
-  Future<Tag> crateApiUtilsTagFromVec({required List<String> vec}) async {
-    return _MockTag(vec);
+  MessageWithTokens _sent({required String pubkey, required int kind, required String content}) {
+    return MessageWithTokens(
+      id: 'sent_${++_callCount}',
+      pubkey: pubkey,
+      kind: kind,
+      createdAt: DateTime.now(),
+      content: content,
+      tokens: const [],
+    );
   }
 
   @override
@@ -37,14 +39,58 @@ class _MockApi extends MockWnApi {
     List<Tag>? tags,
   }) async {
     sentMessages.add((pubkey: pubkey, groupId: groupId, message: message, kind: kind, tags: tags));
-    return MessageWithTokens(
-      id: 'sent_${sentMessages.length}',
-      pubkey: pubkey,
-      kind: kind,
-      createdAt: DateTime.now(),
-      content: message,
-      tokens: const [],
-    );
+    return _sent(pubkey: pubkey, kind: kind, content: message);
+  }
+
+  @override
+  Future<MessageWithTokens> crateApiMessagesSendReply({
+    required String pubkey,
+    required String groupId,
+    required String messageId,
+    required String message,
+  }) async {
+    replies.add((pubkey: pubkey, groupId: groupId, messageId: messageId, content: message));
+    return _sent(pubkey: pubkey, kind: 9, content: message);
+  }
+
+  @override
+  Future<MessageWithTokens> crateApiMessagesSendReaction({
+    required String pubkey,
+    required String groupId,
+    required String messageId,
+    required String emoji,
+  }) async {
+    reactions.add((pubkey: pubkey, groupId: groupId, messageId: messageId, content: emoji));
+    return _sent(pubkey: pubkey, kind: 7, content: emoji);
+  }
+
+  @override
+  Future<MessageWithTokens> crateApiMessagesRemoveReaction({
+    required String pubkey,
+    required String groupId,
+    required String messageId,
+    required String emoji,
+  }) async {
+    reactionRemovals.add((pubkey: pubkey, groupId: groupId, messageId: messageId, content: emoji));
+    return _sent(pubkey: pubkey, kind: 5, content: '');
+  }
+
+  @override
+  Future<MessageWithTokens> crateApiMessagesDeleteMessage({
+    required String pubkey,
+    required String groupId,
+    required String messageId,
+  }) async {
+    deletions.add((pubkey: pubkey, groupId: groupId, messageId: messageId, content: ''));
+    return _sent(pubkey: pubkey, kind: 5, content: '');
+  }
+
+  void clear() {
+    sentMessages.clear();
+    replies.clear();
+    reactions.clear();
+    reactionRemovals.clear();
+    deletions.clear();
   }
 }
 
@@ -58,7 +104,7 @@ void main() {
   });
 
   setUp(() {
-    mockApi.sentMessages.clear();
+    mockApi.clear();
     service = const MessageService(pubkey: _testPubkey, groupId: 'group1');
   });
 
@@ -93,7 +139,7 @@ void main() {
       expect(mockApi.sentMessages.first.kind, 9);
     });
 
-    test('sends message without tags when no reply params', () async {
+    test('sends message without tags', () async {
       await service.sendTextMessage(content: 'Hello');
 
       expect(mockApi.sentMessages.first.tags, isNull);
@@ -102,257 +148,79 @@ void main() {
 
   group('sendTextMessage (reply)', () {
     const testReplyId = 'reply_msg_id';
-    const testReplyPubkey = testPubkeyB;
-    const testReplyKind = 9;
-
-    test('sends message once when reply params provided', () async {
-      await service.sendTextMessage(
-        content: 'Reply content',
-        replyToMessageId: testReplyId,
-        replyToMessagePubkey: testReplyPubkey,
-        replyToMessageKind: testReplyKind,
-      );
 
-      expect(mockApi.sentMessages.length, 1);
-    });
-
-    test('calls API with content when replying', () async {
-      await service.sendTextMessage(
-        content: 'Reply content',
-        replyToMessageId: testReplyId,
-        replyToMessagePubkey: testReplyPubkey,
-        replyToMessageKind: testReplyKind,
-      );
+    test('sends reply once', () async {
+      await service.sendTextMessage(content: 'Reply content', replyToMessageId: testReplyId);
 
-      expect(mockApi.sentMessages.first.message, 'Reply content');
+      expect(mockApi.replies.length, 1);
+      expect(mockApi.sentMessages, isEmpty);
     });
 
-    test('calls API with text message kind (9) when replying', () async {
-      await service.sendTextMessage(
-        content: 'Reply content',
-        replyToMessageId: testReplyId,
-        replyToMessagePubkey: testReplyPubkey,
-        replyToMessageKind: testReplyKind,
-      );
+    test('calls reply API with pubkey and groupId from constructor', () async {
+      await service.sendTextMessage(content: 'Reply content', replyToMessageId: testReplyId);
 
-      expect(mockApi.sentMessages.first.kind, 9);
+      expect(mockApi.replies.first.pubkey, _testPubkey);
+      expect(mockApi.replies.first.groupId, 'group1');
     });
 
-    test('sends e tag with reply message id', () async {
-      await service.sendTextMessage(
-        content: 'Reply content',
-        replyToMessageId: testReplyId,
-        replyToMessagePubkey: testReplyPubkey,
-        replyToMessageKind: testReplyKind,
-      );
+    test('calls reply API with content', () async {
+      await service.sendTextMessage(content: 'Reply content', replyToMessageId: testReplyId);
 
-      final tags = mockApi.sentMessages.first.tags!.cast<_MockTag>();
-      expect(tags[0].vec, ['e', testReplyId]);
+      expect(mockApi.replies.first.content, 'Reply content');
     });
 
-    test('sends p tag with reply message pubkey', () async {
-      await service.sendTextMessage(
-        content: 'Reply content',
-        replyToMessageId: testReplyId,
-        replyToMessagePubkey: testReplyPubkey,
-        replyToMessageKind: testReplyKind,
-      );
+    test('calls reply API with replied message id', () async {
+      await service.sendTextMessage(content: 'Reply content', replyToMessageId: testReplyId);
 
-      final tags = mockApi.sentMessages.first.tags!.cast<_MockTag>();
-      expect(tags[1].vec, ['p', testReplyPubkey, '']);
-    });
-
-    test('sends k tag with reply message kind', () async {
-      await service.sendTextMessage(
-        content: 'Reply content',
-        replyToMessageId: testReplyId,
-        replyToMessagePubkey: testReplyPubkey,
-        replyToMessageKind: testReplyKind,
-      );
-
-      final tags = mockApi.sentMessages.first.tags!.cast<_MockTag>();
-      expect(tags[2].vec, ['k', testReplyKind.toString()]);
-    });
-
-    test('sends no tags when only replyToMessageId provided', () async {
-      await service.sendTextMessage(
-        content: 'Reply content',
-        replyToMessageId: testReplyId,
-      );
-
-      expect(mockApi.sentMessages.first.tags, isNull);
-    });
-
-    test('sends no tags when only replyToMessagePubkey provided', () async {
-      await service.sendTextMessage(
-        content: 'Reply content',
-        replyToMessagePubkey: testReplyPubkey,
-      );
-
-      expect(mockApi.sentMessages.first.tags, isNull);
-    });
-
-    test('sends no tags when only replyToMessageKind provided', () async {
-      await service.sendTextMessage(
-        content: 'Reply content',
-        replyToMessageKind: testReplyKind,
-      );
-
-      expect(mockApi.sentMessages.first.tags, isNull);
+      expect(mockApi.replies.first.messageId, testReplyId);
     });
   });
 
   group('deleteTextMessage', () {
-    test('sends deletion message once', () async {
-      await service.deleteTextMessage(
-        messageId: 'msg123',
-        messagePubkey: testPubkeyB,
-      );
+    test('deletes message once', () async {
+      await service.deleteTextMessage(messageId: 'msg123');
 
-      expect(mockApi.sentMessages.length, 1);
+      expect(mockApi.deletions.length, 1);
     });
 
     test('calls API with pubkey from constructor', () async {
-      await service.deleteTextMessage(
-        messageId: 'msg123',
-        messagePubkey: testPubkeyB,
-      );
+      await service.deleteTextMessage(messageId: 'msg123');
 
-      expect(mockApi.sentMessages.first.pubkey, _testPubkey);
+      expect(mockApi.deletions.first.pubkey, _testPubkey);
     });
 
     test('calls API with groupId from constructor', () async {
-      await service.deleteTextMessage(
-        messageId: 'msg123',
-        messagePubkey: testPubkeyB,
-      );
-
-      expect(mockApi.sentMessages.first.groupId, 'group1');
-    });
+      await service.deleteTextMessage(messageId: 'msg123');
 
-    test('calls API with empty message', () async {
-      await service.deleteTextMessage(
-        messageId: 'msg123',
-        messagePubkey: testPubkeyB,
-      );
-
-      expect(mockApi.sentMessages.first.message, '');
+      expect(mockApi.deletions.first.groupId, 'group1');
     });
 
-    test('calls API with deletion kind (5)', () async {
-      await service.deleteTextMessage(
-        messageId: 'msg123',
-        messagePubkey: testPubkeyB,
-      );
+    test('calls API with messageId', () async {
+      await service.deleteTextMessage(messageId: 'msg123');
 
-      expect(mockApi.sentMessages.first.kind, 5);
-    });
-
-    test('sends e tag with messageId', () async {
-      await service.deleteTextMessage(
-        messageId: 'msg123',
-        messagePubkey: testPubkeyB,
-      );
-
-      final tags = mockApi.sentMessages.first.tags!.cast<_MockTag>();
-      expect(tags[0].vec, ['e', 'msg123']);
-    });
-
-    test('sends p tag with messagePubkey', () async {
-      await service.deleteTextMessage(
-        messageId: 'msg123',
-        messagePubkey: testPubkeyB,
-      );
-
-      final tags = mockApi.sentMessages.first.tags!.cast<_MockTag>();
-      expect(tags[1].vec, ['p', testPubkeyB, '']);
-    });
-
-    test('sends k tag with text message kind (9)', () async {
-      await service.deleteTextMessage(
-        messageId: 'msg123',
-        messagePubkey: testPubkeyB,
-      );
-
-      final tags = mockApi.sentMessages.first.tags!.cast<_MockTag>();
-      expect(tags[2].vec, ['k', '9']);
+      expect(mockApi.deletions.first.messageId, 'msg123');
     });
   });
 
-  group('deleteReaction', () {
-    test('sends deletion message once', () async {
-      await service.deleteReaction(
-        reactionId: 'reaction123',
-        reactionPubkey: testPubkeyB,
-      );
-
-      expect(mockApi.sentMessages.length, 1);
-    });
-
-    test('calls API with pubkey from constructor', () async {
-      await service.deleteReaction(
-        reactionId: 'reaction123',
-        reactionPubkey: testPubkeyB,
-      );
-
-      expect(mockApi.sentMessages.first.pubkey, _testPubkey);
-    });
-
-    test('calls API with groupId from constructor', () async {
-      await service.deleteReaction(
-        reactionId: 'reaction123',
-        reactionPubkey: testPubkeyB,
-      );
-
-      expect(mockApi.sentMessages.first.groupId, 'group1');
-    });
-
-    test('calls API with empty message', () async {
-      await service.deleteReaction(
-        reactionId: 'reaction123',
-        reactionPubkey: testPubkeyB,
-      );
-
-      expect(mockApi.sentMessages.first.message, '');
-    });
-
-    test('calls API with deletion kind (5)', () async {
-      await service.deleteReaction(
-        reactionId: 'reaction123',
-        reactionPubkey: testPubkeyB,
-      );
+  group('removeReaction', () {
+    test('removes reaction once', () async {
+      await service.removeReaction(messageId: 'msg123', emoji: '👍');
 
-      expect(mockApi.sentMessages.first.kind, 5);
+      expect(mockApi.reactionRemovals.length, 1);
     });
 
-    test('sends e tag with reactionId', () async {
-      await service.deleteReaction(
-        reactionId: 'reaction123',
-        reactionPubkey: testPubkeyB,
-      );
+    test('calls API with pubkey and groupId from constructor', () async {
+      await service.removeReaction(messageId: 'msg123', emoji: '👍');
 
-      final tags = mockApi.sentMessages.first.tags!.cast<_MockTag>();
-      expect(tags[0].vec, ['e', 'reaction123']);
+      expect(mockApi.reactionRemovals.first.pubkey, _testPubkey);
+      expect(mockApi.reactionRemovals.first.groupId, 'group1');
     });
 
-    test('sends p tag with reactionPubkey', () async {
-      await service.deleteReaction(
-        reactionId: 'reaction123',
-        reactionPubkey: testPubkeyB,
-      );
+    test('calls API with messageId and emoji', () async {
+      await service.removeReaction(messageId: 'msg123', emoji: '👍');
 
-      final tags = mockApi.sentMessages.first.tags!.cast<_MockTag>();
-      expect(tags[1].vec, ['p', testPubkeyB, '']);
-    });
-
-    test('sends k tag with reaction kind (7)', () async {
-      await service.deleteReaction(
-        reactionId: 'reaction123',
-        reactionPubkey: testPubkeyB,
-      );
-
-      final tags = mockApi.sentMessages.first.tags!.cast<_MockTag>();
-      expect(tags[2].vec, ['k', '7']);
+      expect(mockApi.reactionRemovals.first.messageId, 'msg123');
+      expect(mockApi.reactionRemovals.first.content, '👍');
     });
   });
 
@@ -373,6 +241,7 @@ void main() {
       reactions: reactions ?? const ReactionSummary(byEmoji: [], userReactions: []),
       mediaAttachments: const [],
       kind: 9,
+      editHistory: const [],
     );
 
     test('sends reaction when user has not reacted', () async {
@@ -380,12 +249,12 @@ void main() {
 
       await service.toggleReaction(message: message, emoji: '👍');
 
-      expect(mockApi.sentMessages.length, 1);
-      expect(mockApi.sentMessages.first.kind, 7);
-      expect(mockApi.sentMessages.first.message, '👍');
+      expect(mockApi.reactions.length, 1);
+      expect(mockApi.reactions.first.messageId, 'msg123');
+      expect(mockApi.reactions.first.content, '👍');
     });
 
-    test('deletes reaction when user has already reacted with same emoji', () async {
+    test('removes reaction when user has already reacted with same emoji', () async {
       final message = createMessage(
         reactions: ReactionSummary(
           byEmoji: [
@@ -408,10 +277,10 @@ void main() {
 
       await service.toggleReaction(message: message, emoji: '👍');
 
-      expect(mockApi.sentMessages.length, 1);
-      expect(mockApi.sentMessages.first.kind, 5);
-      final tags = mockApi.sentMessages.first.tags!.cast<_MockTag>();
-      expect(tags[0].vec, ['e', 'reaction_to_delete']);
+      expect(mockApi.reactions, isEmpty);
+      expect(mockApi.reactionRemovals.length, 1);
+      expect(mockApi.reactionRemovals.first.messageId, 'msg123');
+      expect(mockApi.reactionRemovals.first.content, '👍');
     });
 
     test('sends new reaction when user has reacted with different emoji', () async {
@@ -437,9 +306,8 @@ void main() {
 
       await service.toggleReaction(message: message, emoji: '❤');
 
-      expect(mockApi.sentMessages.length, 1);
-      expect(mockApi.sentMessages.first.kind, 7);
-      expect(mockApi.sentMessages.first.message, '❤');
+      expect(mockApi.reactions.length, 1);
+      expect(mockApi.reactions.first.content, '❤');
     });
 
     test('sends reaction when other users have reacted but not current user', () async {
@@ -461,102 +329,40 @@ void main() {
 
       await service.toggleReaction(message: message, emoji: '👍');
 
-      expect(mockApi.sentMessages.length, 1);
-      expect(mockApi.sentMessages.first.kind, 7);
-      expect(mockApi.sentMessages.first.message, '👍');
+      expect(mockApi.reactions.length, 1);
+      expect(mockApi.reactions.first.content, '👍');
     });
   });
 
   group('sendReaction', () {
-    test('sends reaction message once', () async {
-      await service.sendReaction(
-        messageId: 'msg123',
-        messagePubkey: testPubkeyB,
-        messageKind: 9,
-        emoji: '👍',
-      );
+    test('sends reaction once', () async {
+      await service.sendReaction(messageId: 'msg123', emoji: '👍');
 
-      expect(mockApi.sentMessages.length, 1);
+      expect(mockApi.reactions.length, 1);
     });
 
     test('calls API with pubkey from constructor', () async {
-      await service.sendReaction(
-        messageId: 'msg123',
-        messagePubkey: testPubkeyB,
-        messageKind: 9,
-        emoji: '👍',
-      );
+      await service.sendReaction(messageId: 'msg123', emoji: '👍');
 
-      expect(mockApi.sentMessages.first.pubkey, _testPubkey);
+      expect(mockApi.reactions.first.pubkey, _testPubkey);
     });
 
     test('calls API with groupId from constructor', () async {
-      await service.sendReaction(
-        messageId: 'msg123',
-        messagePubkey: testPubkeyB,
-        messageKind: 9,
-        emoji: '👍',
-      );
-
-      expect(mockApi.sentMessages.first.groupId, 'group1');
-    });
-
-    test('calls API with emoji as message', () async {
-      await service.sendReaction(
-        messageId: 'msg123',
-        messagePubkey: testPubkeyB,
-        messageKind: 9,
-        emoji: '🔥',
-      );
-
-      expect(mockApi.sentMessages.first.message, '🔥');
-    });
-
-    test('calls API with reaction kind (7)', () async {
-      await service.sendReaction(
-        messageId: 'msg123',
-        messagePubkey: testPubkeyB,
-        messageKind: 9,
-        emoji: '👍',
-      );
+      await service.sendReaction(messageId: 'msg123', emoji: '👍');
 
-      expect(mockApi.sentMessages.first.kind, 7);
+      expect(mockApi.reactions.first.groupId, 'group1');
     });
 
-    test('sends e tag with messageId', () async {
-      await service.sendReaction(
-        messageId: 'msg123',
-        messagePubkey: testPubkeyB,
-        messageKind: 9,
-        emoji: '👍',
-      );
+    test('calls API with emoji', () async {
+      await service.sendReaction(messageId: 'msg123', emoji: '🔥');
 
-      final tags = mockApi.sentMessages.first.tags!.cast<_MockTag>();
-      expect(tags[0].vec, ['e', 'msg123']);
+      expect(mockApi.reactions.first.content, '🔥');
     });
 
-    test('sends p tag with messagePubkey', () async {
-      await service.sendReaction(
-        messageId: 'msg123',
-        messagePubkey: testPubkeyB,
-        messageKind: 9,
-        emoji: '👍',
-      );
-
-      final tags = mockApi.sentMessages.first.tags!.cast<_MockTag>();
-      expect(tags[1].vec, ['p', testPubkeyB, '']);
-    });
-
-    test('sends k tag with messageKind', () async {
-      await service.sendReaction(
-        messageId: 'msg123',
-        messagePubkey: testPubkeyB,
-        messageKind: 42,
-        emoji: '👍',
-      );
+    test('calls API with messageId', () async {
+      await service.sendReaction(messageId: 'msg123', emoji: '👍');
 
-      final tags = mockApi.sentMessages.first.tags!.cast<_MockTag>();
-      expect(tags[2].vec, ['k', '42']);
+      expect(mockApi.reactions.first.messageId, 'msg123');
     });
   });
 }
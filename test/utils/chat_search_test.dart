@@ -10,6 +10,8 @@ ChatSummary _chatSummary({required String id, String? name, GroupType? groupType
   createdAt: DateTime(2024),
   pendingConfirmation: false,
   unreadCount: BigInt.zero,
+  notificationLevel: NotificationLevel.all,
+  archived: false,
 );
 
 final _chats = [
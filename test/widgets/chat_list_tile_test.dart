@@ -38,6 +38,8 @@ ChatSummary _chatSummary({
   createdAt: DateTime(2024),
   pendingConfirmation: pendingConfirmation,
   unreadCount: BigInt.zero,
+  notificationLevel: NotificationLevel.all,
+  archived: false,
   groupImagePath: groupImagePath,
   groupImageUrl: groupImageUrl,
   welcomerPubkey: welcomerPubkey,
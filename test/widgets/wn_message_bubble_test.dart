@@ -43,6 +43,7 @@ ChatMessage _message({
   reactions: reactions,
   mediaAttachments: const [],
   kind: 9,
+  editHistory: const [],
 );
 
 void main() {
@@ -0,0 +1,107 @@
+import 'package:flutter_test/flutter_test.dart';
+import 'package:whitenoise/src/rust/api/messages.dart';
+import 'package:whitenoise/src/rust/api/metadata.dart';
+import 'package:whitenoise/widgets/wn_timeline_notice.dart';
+import '../test_helpers.dart';
+
+const _names = {
+  testPubkeyA: FlutterMetadata(displayName: 'Alice', custom: {}),
+  testPubkeyB: FlutterMetadata(displayName: 'Bob', custom: {}),
+};
+
+Future<void> _mountNotice(
+  WidgetTester tester,
+  SystemEvent event, {
+  String? currentUserPubkey,
+}) => mountWidget(
+  WnTimelineNotice(
+    event: event,
+    currentUserPubkey: currentUserPubkey,
+    getAuthorMetadata: (pubkey) => _names[pubkey],
+  ),
+  tester,
+);
+
+void main() {
+  group('WnTimelineNotice', () {
+    testWidgets('renders added members', (tester) async {
+      await _mountNotice(
+        tester,
+        const SystemEvent.membersAdded(actor: testPubkeyA, members: [testPubkeyB]),
+      );
+
+      expect(find.text('Alice added Bob'), findsOneWidget);
+    });
+
+    testWidgets('renders removed members', (tester) async {
+      await _mountNotice(
+        tester,
+        const SystemEvent.membersRemoved(actor: testPubkeyA, members: [testPubkeyB]),
+      );
+
+      expect(find.text('Alice removed Bob'), findsOneWidget);
+    });
+
+    testWidgets('renders a member leaving', (tester) async {
+      await _mountNotice(tester, const SystemEvent.memberLeft(actor: testPubkeyB));
+
+      expect(find.text('Bob left the group'), findsOneWidget);
+    });
+
+    testWidgets('renders a rename with the new name', (tester) async {
+      await _mountNotice(
+        tester,
+        const SystemEvent.groupRenamed(actor: testPubkeyA, name: 'Book club'),
+      );
+
+      expect(find.text('Alice renamed the group to Book club'), findsOneWidget);
+    });
+
+    testWidgets('renders a description change', (tester) async {
+      await _mountNotice(
+        tester,
+        const SystemEvent.descriptionChanged(actor: testPubkeyA, description: 'New'),
+      );
+
+      expect(find.text('Alice changed the group description'), findsOneWidget);
+    });
+
+    testWidgets('renders an image change', (tester) async {
+      await _mountNotice(tester, const SystemEvent.imageChanged(actor: testPubkeyA));
+
+      expect(find.text('Alice changed the group image'), findsOneWidget);
+    });
+
+    testWidgets('renders admin promotions and demotions', (tester) async {
+      await _mountNotice(
+        tester,
+        const SystemEvent.adminsChanged(
+          actor: testPubkeyA,
+          added: [testPubkeyB],
+          removed: [testPubkeyC],
+        ),
+      );
+
+      expect(
+        find.text('Alice made Bob admin\nAlice removed Unknown user as admin'),
+        findsOneWidget,
+      );
+    });
+
+    testWidgets('renders You for the current user', (tester) async {
+      await _mountNotice(
+        tester,
+        const SystemEvent.membersAdded(actor: testPubkeyA, members: [testPubkeyB]),
+        currentUserPubkey: testPubkeyA,
+      );
+
+      expect(find.text('You added Bob'), findsOneWidget);
+    });
+
+    testWidgets('renders unknown user without metadata', (tester) async {
+      await _mountNotice(tester, const SystemEvent.memberLeft(actor: testPubkeyC));
+
+      expect(find.text('Unknown user left the group'), findsOneWidget);
+    });
+  });
+}